@@ -0,0 +1,88 @@
+/// SQL dialect in which the statements are rendered, mirroring `worm::Dialect`
+#[derive(Clone, Copy)]
+pub enum Dialect {
+	Sqlite,
+	Postgres,
+}
+
+impl Dialect {
+	pub const ALL: [Dialect; 2] = [Dialect::Sqlite, Dialect::Postgres];
+	
+	/// Placeholder of the parameter at `index`, starting from 1
+	pub fn placeholder(&self, index: usize) -> String {
+		match self {
+			Dialect::Sqlite => format!("?{}", index),
+			Dialect::Postgres => format!("${}", index),
+		}
+	}
+	
+	/// Equality operator holding for two NULL values
+	pub fn null_safe_equal(&self) -> &'static str {
+		match self {
+			Dialect::Sqlite => "IS",
+			Dialect::Postgres => "IS NOT DISTINCT FROM",
+		}
+	}
+	
+	/// Constraints of a primary key generated by the database, following the column type
+	pub fn auto_key(&self) -> &'static str {
+		match self {
+			Dialect::Sqlite => "PRIMARY KEY AUTOINCREMENT",
+			Dialect::Postgres => "GENERATED ALWAYS AS IDENTITY PRIMARY KEY",
+		}
+	}
+	
+	fn to_tokens(self) -> proc_macro2::TokenStream {
+		match self {
+			Dialect::Sqlite => quote::quote! { ::worm::Dialect::Sqlite },
+			Dialect::Postgres => quote::quote! { ::worm::Dialect::Postgres },
+		}
+	}
+}
+
+/// Render a statement for every dialect, as a match on the `dialect` variable of the generated code
+pub fn match_dialect<F: Fn(Dialect) -> String>(statement: F) -> proc_macro2::TokenStream {
+	let arms = Dialect::ALL.iter().map(|dialect| {
+		let variant = dialect.to_tokens();
+		let statement = statement(*dialect);
+		quote::quote! { #variant => #statement }
+	});
+	
+	quote::quote! {
+		match dialect {
+			#( #arms ),*
+		}
+	}
+}
+
+/// Render a statement completed at run time for every dialect, as a match on the `dialect` variable
+/// of the generated code
+///
+/// The rendered statement holds a `{}` for each of its arguments, any other brace being escaped as
+/// in `format!`.
+pub fn match_dialect_format<F>(statement: F) -> proc_macro2::TokenStream
+where
+	F: Fn(Dialect) -> (String, Vec<proc_macro2::TokenStream>),
+{
+	let arms = Dialect::ALL.iter().map(|dialect| {
+		let variant = dialect.to_tokens();
+		match statement(*dialect) {
+			(statement, args) if args.is_empty() => {
+				let statement = statement.replace("{{", "{").replace("}}", "}");
+				quote::quote! { #variant => String::from(#statement) }
+			}
+			(statement, args) => quote::quote! { #variant => format!(#statement, #( #args ),*) },
+		}
+	});
+	
+	quote::quote! {
+		match dialect {
+			#( #arms ),*
+		}
+	}
+}
+
+/// Escape the braces of a part of a statement rendered by `match_dialect_format`
+pub fn escape_format(part: &str) -> String {
+	part.replace('{', "{{").replace('}', "}}")
+}
@@ -0,0 +1,53 @@
+use crate::input::Input;
+
+/// Flavour of a generated table. The methods of the table running on a synchronous connection and
+/// of the table running on an sqlx pool are generated from the same code, and only differ by the
+/// way they call the connection.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Execution {
+	Sync,
+	Async,
+}
+
+impl Execution {
+	/// Flavours generated with the enabled features
+	pub fn enabled() -> Vec<Execution> {
+		if cfg!(feature = "sqlx") {
+			vec![Execution::Sync, Execution::Async]
+		} else {
+			vec![Execution::Sync]
+		}
+	}
+	
+	/// Table struct of the flavour
+	pub fn table(self, input: &Input) -> syn::Ident {
+		match self {
+			Execution::Sync => input.name_table.clone(),
+			Execution::Async => input.ident("AsyncTable"),
+		}
+	}
+	
+	/// Signature of a method of `worm::Table`, which the asynchronous table has as inherent method
+	pub fn table_fn(self) -> proc_macro2::TokenStream {
+		match self {
+			Execution::Sync => quote::quote! { fn },
+			Execution::Async => quote::quote! { pub async fn },
+		}
+	}
+	
+	/// Signature of an inherent method
+	pub fn inherent_fn(self) -> proc_macro2::TokenStream {
+		match self {
+			Execution::Sync => quote::quote! { pub fn },
+			Execution::Async => quote::quote! { pub async fn },
+		}
+	}
+	
+	/// Suffix of a call to the connection or to another method of the table
+	pub fn wait(self) -> proc_macro2::TokenStream {
+		match self {
+			Execution::Sync => quote::quote! {},
+			Execution::Async => quote::quote! { .await },
+		}
+	}
+}
@@ -0,0 +1,99 @@
+use crate::execution::Execution;
+use crate::input::Input;
+
+/// Generate the changeset of the columns outside of the primary key, and the update of the
+/// columns populated in a changeset
+pub fn impl_changeset(input: &Input) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let changeset = input.ident("Changeset");
+	let filter = input.ident("Filter");
+	let vis = input.vis();
+	let statement = format!("UPDATE {} SET ", input.get_table_name());
+	
+	let fields: Vec<_> = input.fields_iter().filter(|f| !f.primary_key).collect();
+	let idents: Vec<&syn::Ident> = fields.iter().map(|f| f.ident).collect();
+	let types = fields.iter().map(|f| {
+		let ty = f.value_ty;
+		if f.optional {
+			quote::quote! { Option<Option<#ty>> }
+		} else {
+			quote::quote! { Option<#ty> }
+		}
+	});
+	let set = fields.iter().map(|f| {
+		let ident = f.ident;
+		let column = &f.column;
+		let value = f.bind_value(quote::quote! { v });
+		quote::quote! {
+			if let Some(v) = &changeset.#ident {
+				params.push(#value);
+				set.push(format!("{} = {}", #column, dialect.placeholder(params.len())));
+			}
+		}
+	});
+	let doc = format!(
+		r#"
+Columns of {} updated by `update_where`, the columns left to `None` keep their value
+
+A nullable column is set to NULL with `Some(None)`.
+"#,
+		name
+	);
+	let methods = Execution::enabled().into_iter().map(|exec| table_changeset(input, exec));
+	
+	quote::quote! {
+		#[doc = #doc]
+		#[derive(Clone, Default)]
+		#vis struct #changeset {
+			#( pub #idents: #types, )*
+		}
+		
+		impl #name {
+			pub fn update_where(
+				filter: &#filter,
+				changeset: &#changeset,
+			) -> ::worm::Result<Option<(String, Vec<::worm::Value>)>> {
+				#name::update_where_for(::worm::Dialect::Sqlite, filter, changeset)
+			}
+			
+			/// Statement updating the populated columns of `changeset`, or `None` when there are none
+			pub fn update_where_for(
+				dialect: ::worm::Dialect,
+				filter: &#filter,
+				changeset: &#changeset,
+			) -> ::worm::Result<Option<(String, Vec<::worm::Value>)>> {
+				let mut set: Vec<String> = vec![];
+				let mut params = vec![];
+				#( #set )*
+				if set.is_empty() {
+					return Ok(None);
+				}
+				let condition = #filter::to_condition(filter, dialect, &mut params)?;
+				Ok(Some((format!("{}{} WHERE {}", #statement, set.join(", "), condition), params)))
+			}
+		}
+		
+		#( #methods )*
+	}
+}
+
+fn table_changeset(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let table = exec.table(input);
+	let changeset = input.ident("Changeset");
+	let filter = input.ident("Filter");
+	let (inherent_fn, wait) = (exec.inherent_fn(), exec.wait());
+	
+	quote::quote! {
+		impl<'a> #table<'a> {
+			/// Update the populated columns of `changeset` in the rows matching `filter`, returning
+			/// the number of rows updated
+			#inherent_fn update_where(&self, filter: #filter, changeset: #changeset) -> ::worm::Result<usize> {
+				match #name::update_where_for(self.connection.dialect(), &filter, &changeset)? {
+					Some((statement, params)) => self.connection.execute(statement.as_str(), &params)#wait,
+					None => Ok(0),
+				}
+			}
+		}
+	}
+}
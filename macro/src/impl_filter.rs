@@ -1,15 +1,17 @@
 use crate::input::field::Field;
 use crate::input::Input;
-use crate::SqlType;
 use convert_case::Casing;
 use proc_macro2::TokenStream;
 
 type FnStrToIdent = Box<dyn Fn(&str) -> syn::Ident>;
+type FieldPredicate = fn(&Field) -> bool;
 
 pub fn impl_filter(input: &Input) -> proc_macro2::TokenStream {
 	let doc = r#"
 Provides ability to nominate the filtering of results as part of the database query, aka WHERE in SQL queries
 "#;
+	let filter = input.ident("Filter");
+	let vis = input.vis();
 	
 	let gt = |na: &str| {
 		syn::Ident::new(
@@ -32,6 +34,14 @@ Provides ability to nominate the filtering of results as part of the database qu
 	};
 	let lt =
 		|na: &str| syn::Ident::new(&format!("{}LowerThan", na), proc_macro2::Span::call_site());
+	let is_null =
+		|na: &str| syn::Ident::new(&format!("{}IsNull", na), proc_macro2::Span::call_site());
+	let is_not_null =
+		|na: &str| syn::Ident::new(&format!("{}IsNotNull", na), proc_macro2::Span::call_site());
+	let is_true =
+		|na: &str| syn::Ident::new(&format!("{}IsTrue", na), proc_macro2::Span::call_site());
+	let is_false =
+		|na: &str| syn::Ident::new(&format!("{}IsFalse", na), proc_macro2::Span::call_site());
 	
 	let fields = &input
 		.fields_iter()
@@ -39,67 +49,132 @@ Provides ability to nominate the filtering of results as part of the database qu
 			(
 				field,
 				format!("{}", field.ident).to_case(convert_case::Case::Pascal),
-				format!("{}", field.ident),
+				field.column.clone(),
 			)
 		})
 		.collect::<Vec<(&Field, String, String)>>();
 	
-	let operator: Vec<FnStrToIdent> = vec![
-		Box::new(gt),
-		Box::new(ge),
-		Box::new(eq),
-		Box::new(le),
-		Box::new(lt),
+	// Equality applies to every column, the other comparisons only to ordered types
+	let operators: Vec<(FnStrToIdent, &str)> = vec![
+		(Box::new(gt), ">"),
+		(Box::new(ge), ">="),
+		(Box::new(eq), "="),
+		(Box::new(le), "<="),
+		(Box::new(lt), "<"),
 	];
-	let q_enum_types = operator
+	let compared_fields = |op: &str| {
+		let equality = op == "=";
+		fields
+			.iter()
+			.filter(move |(field, _, _)| equality || field.sql_type.is_ordered())
+	};
+	let q_enum_types = operators
 		.iter()
-		.flat_map(|func| {
-			fields.iter().map(|(field, name, _name_orig)| {
+		.flat_map(|(func, op)| {
+			compared_fields(op).map(|(field, name, _column)| {
 				let ident = func(name.as_str());
-				let ty = field.ty;
+				let ty = field.value_ty;
 				quote::quote! { #ident(#ty) }
 			})
 		})
 		.collect::<Vec<TokenStream>>();
+	
+	// Operators without value, each applying to the columns matching its predicate
+	let unit_operators: Vec<(FnStrToIdent, &str, FieldPredicate)> = vec![
+		(Box::new(is_null), "IS NULL", |field| field.optional),
+		(Box::new(is_not_null), "IS NOT NULL", |field| field.optional),
+		(Box::new(is_true), "IS TRUE", |field| field.sql_type.is_boolean()),
+		(Box::new(is_false), "IS FALSE", |field| field.sql_type.is_boolean()),
+	];
+	let q_unit_types = unit_operators
+		.iter()
+		.flat_map(|(func, _, applies)| {
+			fields
+				.iter()
+				.filter(|(field, _, _)| applies(field))
+				.map(|(_, name, _)| func(name.as_str()))
+		})
+		.collect::<Vec<syn::Ident>>();
+	
+	// Equality of the value found at a path of the document of a JSON column, e.g. `$.a.b`
+	let path_equal =
+		|na: &str| syn::Ident::new(&format!("{}PathEqual", na), proc_macro2::Span::call_site());
+	let json_fields = fields
+		.iter()
+		.filter(|(field, _, _)| field.sql_type.is_json())
+		.map(|(_, name, column)| (path_equal(name.as_str()), column))
+		.collect::<Vec<(syn::Ident, &String)>>();
+	let q_path_types = json_fields.iter().map(|(ident, _)| ident);
+	
 	let q_enum = quote::quote! {
 		#[doc = #doc]
 		#[derive(Clone)]
-		pub enum Filter {
-			And(Box<Filter>, Box<Filter>),
-			Or(Box<Filter>, Box<Filter>),
-			#( #q_enum_types ),*
+		#vis enum #filter {
+			And(Box<#filter>, Box<#filter>),
+			Or(Box<#filter>, Box<#filter>),
+			#( #q_enum_types, )*
+			#( #q_unit_types, )*
+			#( #q_path_types(String, ::worm::JsonValue), )*
 		}
 	};
 	
-	let operators: Vec<(FnStrToIdent, &str)> = vec![
-		(Box::new(gt), ">"),
-		(Box::new(ge), ">="),
-		(Box::new(eq), "="),
-		(Box::new(le), "<="),
-		(Box::new(lt), "<"),
-	];
 	let q_filter_matcher = operators
 		.iter()
 		.flat_map(|(func, op)| {
-			fields.iter()
-			.map(move |(field, name, name_orig)| {
+			let filter = &filter;
+			compared_fields(op)
+			.map(move |(field, name, column)| {
 				let ident = func(name.as_str());
-				match field.sql_type {
-					SqlType::Text | SqlType::Varchar(_)
-						=> quote::quote! { Filter::#ident(v) => format!("{} {} '{}'", #name_orig, #op, v) },
-					SqlType::Integer
-						=> quote::quote! { Filter::#ident(v) => format!("{} {} {}", #name_orig, #op, v) },
+				let value = field.bind_value(quote::quote! { v });
+				quote::quote! {
+					#filter::#ident(v) => {
+						params.push(#value);
+						format!("{} {} {}", #column, #op, dialect.placeholder(params.len()))
+					}
 				}
 			 })
 		}).collect::<Vec<TokenStream>>();
-	let q_impl = quote::quote! {
-		impl Filter {
-			pub fn to_condition(filter: &Filter) -> String {
-				match filter {
-					Filter::And(a, b) => format!("({} AND {})", Filter::to_condition(a), Filter::to_condition(b)),
-					Filter::Or(a, b)  => format!("({} OR  {})", Filter::to_condition(a), Filter::to_condition(b)),
-					#( #q_filter_matcher ),*
+	let q_unit_matcher = unit_operators
+		.iter()
+		.flat_map(|(func, op, applies)| {
+			let filter = &filter;
+			fields.iter().filter(|(field, _, _)| applies(field)).map(move |(_, name, column)| {
+				let ident = func(name.as_str());
+				quote::quote! {
+					#filter::#ident => format!("{} {}", #column, #op)
 				}
+			})
+		})
+		.collect::<Vec<TokenStream>>();
+	let q_path_matcher = json_fields.iter().map(|(ident, column)| {
+		quote::quote! {
+			#filter::#ident(path, v) => ::worm::json::path_equal(dialect, #column, path, v, params)
+		}
+	});
+	let q_impl = quote::quote! {
+		impl #filter {
+			/// Render the filter as an SQL condition, pushing the value of every comparison to
+			/// `params` in the order of its placeholder, or fail when a value can't be converted
+			pub fn to_condition(
+				filter: &#filter,
+				dialect: ::worm::Dialect,
+				params: &mut Vec<::worm::Value>,
+			) -> ::worm::Result<String> {
+				Ok(match filter {
+					#filter::And(a, b) => {
+						let a = #filter::to_condition(a, dialect, params)?;
+						let b = #filter::to_condition(b, dialect, params)?;
+						format!("({} AND {})", a, b)
+					}
+					#filter::Or(a, b) => {
+						let a = #filter::to_condition(a, dialect, params)?;
+						let b = #filter::to_condition(b, dialect, params)?;
+						format!("({} OR  {})", a, b)
+					}
+					#( #q_filter_matcher, )*
+					#( #q_unit_matcher, )*
+					#( #q_path_matcher, )*
+				})
 			}
 		}
 	};
@@ -115,29 +190,41 @@ Provides ability to nominate the filtering of results as part of the database qu
 
 fn impl_from_for_filter(input: &Input) -> proc_macro2::TokenStream {
 	let ident = &input.ast.ident;
+	let filter = input.ident("Filter");
 	
 	let eq = |na: &str| syn::Ident::new(&format!("{}Equal", na), proc_macro2::Span::call_site());
+	let is_null =
+		|na: &str| syn::Ident::new(&format!("{}IsNull", na), proc_macro2::Span::call_site());
 	
 	let q = input
 		.fields_iter()
 		.map(|field| {
 			let ident = field.ident;
-			let filter_ident = eq(format!("{}", ident)
-				.to_case(convert_case::Case::Pascal)
-				.as_str());
-			quote::quote! {
-				Filter::#filter_ident(item.#ident)
+			let name = format!("{}", ident).to_case(convert_case::Case::Pascal);
+			let filter_ident = eq(name.as_str());
+			if field.optional {
+				let is_null_ident = is_null(name.as_str());
+				quote::quote! {
+					match item.#ident {
+						Some(v) => #filter::#filter_ident(v),
+						None => #filter::#is_null_ident,
+					}
+				}
+			} else {
+				quote::quote! {
+					#filter::#filter_ident(item.#ident)
+				}
 			}
 		})
 		.fold(None, |acc, q| match acc {
 			None => Some(q),
-			Some(o) => Some(quote::quote! { Filter::And( Box::new(#o), Box::new(#q) ) }),
+			Some(o) => Some(quote::quote! { #filter::And( Box::new(#o), Box::new(#q) ) }),
 		})
 		.expect("Unable to construct filter");
 	
 	quote::quote! {
-		impl From<#ident> for Filter {
-			fn from(item: #ident) -> Filter {
+		impl From<#ident> for #filter {
+			fn from(item: #ident) -> #filter {
 				#q
 			}
 		}
@@ -1,13 +1,8 @@
 use crate::input::Input;
-use proc_macro2::Span;
 
-pub fn impl_filter_wrapper(
-	input: &Input,
-	struct_name: &str,
-	span: &Span,
-) -> proc_macro2::TokenStream {
-	let struct_ident = syn::Ident::new(struct_name, *span);
-	let builder_ident = syn::Ident::new((struct_name.to_owned() + "Builder").as_str(), *span);
+pub fn impl_filter_wrapper(input: &Input, struct_name: &str) -> proc_macro2::TokenStream {
+	let struct_ident = input.ident(struct_name);
+	let builder_ident = input.ident((struct_name.to_owned() + "Builder").as_str());
 	
 	let impl_struct = impl_struct(input, &struct_ident);
 	let impl_from_filter = impl_from_filter(input, &struct_ident, &builder_ident);
@@ -22,10 +17,13 @@ pub fn impl_filter_wrapper(
 	}
 }
 
-fn impl_struct(_input: &Input, struct_ident: &syn::Ident) -> proc_macro2::TokenStream {
+fn impl_struct(input: &Input, struct_ident: &syn::Ident) -> proc_macro2::TokenStream {
+	let filter = input.ident("Filter");
+	let vis = input.vis();
+	
 	quote::quote! {
-		pub struct #struct_ident {
-			pub filter: Option<Filter>,
+		#vis struct #struct_ident {
+			pub filter: Option<#filter>,
 		}
 	}
 }
@@ -36,12 +34,13 @@ fn impl_from_struct(
 	builder_ident: &syn::Ident,
 ) -> proc_macro2::TokenStream {
 	let ident = &input.ast.ident;
+	let filter = input.ident("Filter");
 	
 	quote::quote! {
 		impl From<#ident> for #struct_ident {
 			fn from(item: #ident) -> Self {
 				#builder_ident::default()
-					.set_filter(Filter::from(item))
+					.set_filter(#filter::from(item))
 					.build()
 			}
 		}
@@ -49,13 +48,15 @@ fn impl_from_struct(
 }
 
 fn impl_from_filter(
-	_input: &Input,
+	input: &Input,
 	struct_ident: &syn::Ident,
 	builder_ident: &syn::Ident,
 ) -> proc_macro2::TokenStream {
+	let filter = input.ident("Filter");
+	
 	quote::quote! {
-		impl From<Filter> for #struct_ident {
-			fn from(filter: Filter) -> Self {
+		impl From<#filter> for #struct_ident {
+			fn from(filter: #filter) -> Self {
 				#builder_ident::default()
 					.set_filter(filter)
 					.build()
@@ -65,18 +66,21 @@ fn impl_from_filter(
 }
 
 fn impl_builder(
-	_input: &Input,
+	input: &Input,
 	struct_ident: &syn::Ident,
 	builder_ident: &syn::Ident,
 ) -> proc_macro2::TokenStream {
+	let filter = input.ident("Filter");
+	let vis = input.vis();
+	
 	quote::quote! {
 		#[derive(Default)]
-		pub struct #builder_ident {
-			filter: Option<Filter>,
+		#vis struct #builder_ident {
+			filter: Option<#filter>,
 		}
 		
 		impl #builder_ident {
-			pub fn set_filter(mut self, filter: Filter) -> #builder_ident {
+			pub fn set_filter(mut self, filter: #filter) -> #builder_ident {
 				self.filter = Some(filter); self
 			}
 			
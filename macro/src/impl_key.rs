@@ -0,0 +1,103 @@
+use crate::execution::Execution;
+use crate::input::Input;
+use convert_case::Casing;
+
+/// Generate the struct holding the primary key of a row, with the operations identifying a row by
+/// its key. Nothing is generated for a table without primary key.
+pub fn impl_key(input: &Input) -> proc_macro2::TokenStream {
+	let primary_keys = input.primary_keys();
+	if primary_keys.is_empty() {
+		return quote::quote! {};
+	}
+	
+	let name = input.name;
+	let key = input.ident("Key");
+	let filter = input.ident("Filter");
+	let vis = input.vis();
+	let idents: Vec<&syn::Ident> = primary_keys.iter().map(|f| f.ident).collect();
+	let types: Vec<&syn::Type> = primary_keys.iter().map(|f| f.value_ty).collect();
+	let doc = format!(
+		"Primary key of a row of {}, identifying the row in `get_by_key`, `update_by_key` and `delete_by_key`",
+		name
+	);
+	
+	let condition = idents
+		.iter()
+		.map(|ident| {
+			let equal = syn::Ident::new(
+				&format!("{}Equal", ident.to_string().to_case(convert_case::Case::Pascal)),
+				proc_macro2::Span::call_site(),
+			);
+			quote::quote! { #filter::#equal(key.#ident) }
+		})
+		.reduce(|acc, q| quote::quote! { #filter::And(Box::new(#acc), Box::new(#q)) })
+		.expect("Unable to construct filter");
+	
+	let key_parameters = input.to_key_values(quote::quote! { key });
+	let obj_parameters = input.to_written_values(quote::quote! { obj });
+	let methods = Execution::enabled().into_iter().map(|exec| table_key(input, exec));
+	
+	quote::quote! {
+		#[doc = #doc]
+		#[derive(Clone)]
+		#vis struct #key {
+			#( pub #idents: #types, )*
+		}
+		
+		impl From<&#name> for #key {
+			fn from(item: &#name) -> #key {
+				#key {
+					#( #idents: item.#idents.clone(), )*
+				}
+			}
+		}
+		
+		impl From<#key> for #filter {
+			fn from(key: #key) -> #filter {
+				#condition
+			}
+		}
+		
+		impl #name {
+			/// Statement updating the row identified by `key` with the values of `obj`, and its values
+			fn bind_update_by_key(dialect: ::worm::Dialect, key: &#key, obj: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				let params = vec![
+					#( #key_parameters, )*
+					#( #obj_parameters, )*
+				];
+				Ok((#name::update_by_id_for(dialect), params))
+			}
+		}
+		
+		#( #methods )*
+	}
+}
+
+fn table_key(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let table = exec.table(input);
+	let key = input.ident("Key");
+	let filter = input.ident("Filter");
+	let (inherent_fn, wait) = (exec.inherent_fn(), exec.wait());
+	
+	quote::quote! {
+		impl<'a> #table<'a> {
+			/// Select the row identified by `key`
+			#inherent_fn get_by_key(&self, key: #key) -> ::worm::Result<Option<#name>> {
+				self.select_one(#filter::from(key).into())#wait
+			}
+			
+			/// Update the row identified by `key` with the values of `obj`, which may change the key
+			#inherent_fn update_by_key(&self, key: &#key, obj: &#name) -> ::worm::Result<usize> {
+				let (statement, params) = #name::bind_update_by_key(self.connection.dialect(), key, obj)?;
+				let count = self.connection.execute(statement.as_str(), &params)#wait?;
+				self.updated_row_count(count)
+			}
+			
+			/// Delete the row identified by `key`
+			#inherent_fn delete_by_key(&self, key: #key) -> ::worm::Result<()> {
+				self.delete(#filter::from(key).into())#wait
+			}
+		}
+	}
+}
@@ -0,0 +1,42 @@
+use crate::input::Input;
+use convert_case::Casing;
+
+pub fn impl_order_by(input: &Input) -> proc_macro2::TokenStream {
+	let doc = r#"
+Provides ability to nominate the sorting of results as part of the database query, aka ORDER BY in SQL queries
+"#;
+	let order_by = input.ident("OrderBy");
+	let vis = input.vis();
+	
+	let variants = input
+		.fields_iter()
+		.flat_map(|field| {
+			let name = format!("{}", field.ident).to_case(convert_case::Case::Pascal);
+			[("Asc", "ASC"), ("Desc", "DESC")].map(|(suffix, direction)| {
+				(
+					syn::Ident::new(&format!("{}{}", name, suffix), proc_macro2::Span::call_site()),
+					format!("{} {}", field.column, direction),
+				)
+			})
+		})
+		.collect::<Vec<(syn::Ident, String)>>();
+	let idents = variants.iter().map(|(ident, _)| ident).collect::<Vec<_>>();
+	let terms = variants.iter().map(|(_, term)| term).collect::<Vec<_>>();
+	
+	quote::quote! {
+		#[doc = #doc]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+		#vis enum #order_by {
+			#( #idents, )*
+		}
+		
+		impl #order_by {
+			/// Render the sort key as a term of an SQL ORDER BY clause
+			pub fn to_term(&self) -> &'static str {
+				match self {
+					#( #order_by::#idents => #terms, )*
+				}
+			}
+		}
+	}
+}
@@ -15,29 +15,40 @@ pub fn impl_select(input: &Input) -> proc_macro2::TokenStream {
 /*
  *
  */
-fn impl_struct(_input: &Input) -> proc_macro2::TokenStream {
+fn impl_struct(input: &Input) -> proc_macro2::TokenStream {
+	let select = input.ident("Select");
+	let filter = input.ident("Filter");
+	let order_by = input.ident("OrderBy");
+	let vis = input.vis();
+	
 	quote::quote! {
-		pub struct Select {
-			pub filter: Option<Filter>,
+		#vis struct #select {
+			pub filter: Option<#filter>,
+			pub order_by: Vec<#order_by>,
 			pub limit: Option<usize>,
 			pub offset: Option<usize>,
 		}
 	}
 }
 
-fn impl_from(_input: &Input) -> proc_macro2::TokenStream {
+fn impl_from(input: &Input) -> proc_macro2::TokenStream {
+	let select = input.ident("Select");
+	let select_builder = input.ident("SelectBuilder");
+	let select_one = input.ident("SelectOne");
+	let filter = input.ident("Filter");
+	
 	quote::quote! {
-		impl From<Filter> for Select {
-			fn from(filter: Filter) -> Self {
-				SelectBuilder::default()
+		impl From<#filter> for #select {
+			fn from(filter: #filter) -> Self {
+				#select_builder::default()
 					.set_filter(filter)
 					.build()
 			}
 		}
 		
-		impl From<SelectOne> for Select {
-			fn from(select_one: SelectOne) -> Self {
-				let mut b = SelectBuilder::default();
+		impl From<#select_one> for #select {
+			fn from(select_one: #select_one) -> Self {
+				let mut b = #select_builder::default();
 				if let Some(filter) = select_one.filter { b = b.set_filter(filter); }
 				b.set_limit(1)
 					.build()
@@ -46,30 +57,47 @@ fn impl_from(_input: &Input) -> proc_macro2::TokenStream {
 	}
 }
 
-fn impl_builder(_input: &Input) -> proc_macro2::TokenStream {
+fn impl_builder(input: &Input) -> proc_macro2::TokenStream {
+	let select = input.ident("Select");
+	let select_builder = input.ident("SelectBuilder");
+	let filter = input.ident("Filter");
+	let order_by = input.ident("OrderBy");
+	let vis = input.vis();
+	
 	quote::quote! {
 		#[derive(Default)]
-		pub struct SelectBuilder {
-			filter: Option<Filter>,
+		#vis struct #select_builder {
+			filter: Option<#filter>,
+			order_by: Vec<#order_by>,
 			limit: Option<usize>,
 			offset: Option<usize>,
 		}
 		
-		impl SelectBuilder {
-			pub fn set_filter(mut self, filter: Filter) -> SelectBuilder {
+		impl #select_builder {
+			pub fn set_filter(mut self, filter: #filter) -> #select_builder {
 				self.filter = Some(filter); self
 			}
 			
-			pub fn set_limit(mut self, limit: usize) -> SelectBuilder {
+			/// Sort the results by `order_by`, replacing any previous sort key
+			pub fn order_by(mut self, order_by: #order_by) -> #select_builder {
+				self.order_by = vec![order_by]; self
+			}
+			
+			/// Sort the results by `order_by` among the rows equal on the previous sort keys
+			pub fn then_by(mut self, order_by: #order_by) -> #select_builder {
+				self.order_by.push(order_by); self
+			}
+			
+			pub fn set_limit(mut self, limit: usize) -> #select_builder {
 				self.limit = Some(limit); self
 			}
 			
-			pub fn set_offset(mut self, offset: usize) -> SelectBuilder {
+			pub fn set_offset(mut self, offset: usize) -> #select_builder {
 				self.offset = Some(offset); self
 			}
 			
-			pub fn build(self) -> Select {
-				Select { filter: self.filter, limit: self.limit, offset: self.offset }
+			pub fn build(self) -> #select {
+				#select { filter: self.filter, order_by: self.order_by, limit: self.limit, offset: self.offset }
 			}
 		}
 	}
@@ -0,0 +1,188 @@
+use crate::dialect::match_dialect;
+use crate::execution::Execution;
+use crate::input::field::Field;
+use crate::input::Input;
+use crate::worm_table::insert_into;
+
+/// Generate the upsert of a row, which inserts the row or updates the row it conflicts with, and
+/// the insert ignoring a conflicting row. The upsert is left out of a table without unique column
+/// and whose primary key is missing or auto, which has no conflict target.
+pub fn impl_upsert(input: &Input) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let column = input.ident("Column");
+	let upsert = input.ident("Upsert");
+	let upsert_builder = input.ident("UpsertBuilder");
+	let vis = input.vis();
+	
+	let variants: Vec<syn::Ident> = input.fields_iter().map(Field::variant).collect();
+	let columns: Vec<&String> = input.fields_iter().map(|f| &f.column).collect();
+	// The key of a row conflicting on another column is not rewritten by default
+	let written: Vec<syn::Ident> = input.written_fields().filter(|f| !f.primary_key).map(Field::variant).collect();
+	// The conflict target defaults to the primary key, or else to the first unique column. An auto
+	// key is never inserted, so a row cannot conflict on it.
+	let primary_keys: Vec<&Field> = input.primary_keys().into_iter().filter(|f| !f.auto).collect();
+	let target: Vec<syn::Ident> = if primary_keys.is_empty() {
+		input.fields_iter().find(|f| f.unique).map(Field::variant).into_iter().collect()
+	} else {
+		primary_keys.into_iter().map(Field::variant).collect()
+	};
+	
+	let statement = match_dialect(|dialect| insert_into(input, dialect));
+	let statement_or_ignore = match_dialect(|dialect| insert_into(input, dialect) + " ON CONFLICT DO NOTHING");
+	// The row conflicts on its own key, a nil uuid key is not replaced by a generated one
+	let upsert_parameters = input.to_written_values(quote::quote! { i });
+	let parameters = input.to_insert_values(quote::quote! { i });
+	let methods: Vec<_> = Execution::enabled()
+		.into_iter()
+		.map(|exec| table_upsert(input, exec, !target.is_empty()))
+		.collect();
+	
+	let doc_column = format!("Column of {}, nominating the conflict target and the update set of an upsert", name);
+	let doc_upsert = r#"
+Conflict target and update set of an upsert, built with the upsert builder
+
+The target defaults to the primary key, or to the first unique column of a table whose primary key
+is missing or auto.
+The update set defaults to every column outside of the target and of the primary key. The conflicting
+row is ignored when asked for with `do_nothing`, or when no column is left to update.
+"#;
+	
+	let impl_insert_or_ignore = quote::quote! {
+		#[doc = #doc_column]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+		#vis enum #column {
+			#( #variants, )*
+		}
+		
+		impl #column {
+			/// Name of the SQL column
+			pub fn name(&self) -> &'static str {
+				match self {
+					#( #column::#variants => #columns, )*
+				}
+			}
+		}
+		
+		impl #name {
+			pub fn insert_or_ignore() -> String {
+				#name::insert_or_ignore_for(::worm::Dialect::Sqlite)
+			}
+			
+			pub fn insert_or_ignore_for(dialect: ::worm::Dialect) -> String {
+				String::from(#statement_or_ignore)
+			}
+			
+			/// Statement inserting `i` unless it conflicts with a row, and its values
+			fn bind_insert_or_ignore(dialect: ::worm::Dialect, i: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				Ok((#name::insert_or_ignore_for(dialect), vec![ #( #parameters ),* ]))
+			}
+		}
+		
+		#( #methods )*
+	};
+	// DO UPDATE needs a conflict target
+	if target.is_empty() {
+		return impl_insert_or_ignore;
+	}
+	
+	quote::quote! {
+		#impl_insert_or_ignore
+		
+		#[doc = #doc_upsert]
+		#[derive(Clone, Default)]
+		#vis struct #upsert {
+			target: Option<Vec<#column>>,
+			update: Option<Vec<#column>>,
+		}
+		
+		#[derive(Default)]
+		#vis struct #upsert_builder {
+			target: Option<Vec<#column>>,
+			update: Option<Vec<#column>>,
+		}
+		
+		impl #upsert_builder {
+			/// Add `column` to the conflict target, replacing the default target
+			pub fn on_conflict(mut self, column: #column) -> #upsert_builder {
+				self.target.get_or_insert_with(Vec::new).push(column); self
+			}
+			
+			/// Add `column` to the update set, replacing the default update set
+			pub fn update(mut self, column: #column) -> #upsert_builder {
+				self.update.get_or_insert_with(Vec::new).push(column); self
+			}
+			
+			/// Ignore the conflicting row instead of updating it
+			pub fn do_nothing(mut self) -> #upsert_builder {
+				self.update = Some(vec![]); self
+			}
+			
+			pub fn build(self) -> #upsert {
+				#upsert { target: self.target, update: self.update }
+			}
+		}
+		
+		impl #name {
+			pub fn upsert(upsert: &#upsert) -> String {
+				#name::upsert_for(::worm::Dialect::Sqlite, upsert)
+			}
+			
+			pub fn upsert_for(dialect: ::worm::Dialect, upsert: &#upsert) -> String {
+				let target = upsert.target.clone().unwrap_or_else(|| vec![ #( #column::#target ),* ]);
+				let update = upsert.update.clone().unwrap_or_else(|| {
+					[ #( #column::#written ),* ].into_iter().filter(|c| !target.contains(c)).collect()
+				});
+				let target: Vec<&str> = target.iter().map(|c| c.name()).collect();
+				let action = if update.is_empty() {
+					String::from("DO NOTHING")
+				} else {
+					let set: Vec<String> = update.iter().map(|c| format!("{} = excluded.{}", c.name(), c.name())).collect();
+					format!("DO UPDATE SET {}", set.join(", "))
+				};
+				format!("{} ON CONFLICT ({}) {}", #statement, target.join(", "), action)
+			}
+			
+			/// Statement of the upsert of `i`, and its values
+			fn bind_upsert(dialect: ::worm::Dialect, upsert: &#upsert, i: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				Ok((#name::upsert_for(dialect, upsert), vec![ #( #upsert_parameters ),* ]))
+			}
+		}
+	}
+}
+
+fn table_upsert(input: &Input, exec: Execution, has_target: bool) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let table = exec.table(input);
+	let upsert = input.ident("Upsert");
+	let (inherent_fn, wait) = (exec.inherent_fn(), exec.wait());
+	
+	let impl_upsert = if has_target {
+		quote::quote! {
+			/// Insert `i`, or update the row sharing its primary key with the other columns of `i`,
+			/// returning the number of rows inserted or updated
+			#inherent_fn upsert(&self, i: &#name) -> ::worm::Result<usize> {
+				self.upsert_with(i, #upsert::default())#wait
+			}
+			
+			/// Insert `i`, or update the row conflicting on the target of `upsert` with its update set
+			#inherent_fn upsert_with(&self, i: &#name, upsert: #upsert) -> ::worm::Result<usize> {
+				let (statement, params) = #name::bind_upsert(self.connection.dialect(), &upsert, i)?;
+				self.connection.execute(statement.as_str(), &params)#wait
+			}
+		}
+	} else {
+		quote::quote! {}
+	};
+	
+	quote::quote! {
+		impl<'a> #table<'a> {
+			#impl_upsert
+			
+			/// Insert `i` unless it conflicts with a row, returning whether it was inserted
+			#inherent_fn insert_or_ignore(&self, i: &#name) -> ::worm::Result<bool> {
+				let (statement, params) = #name::bind_insert_or_ignore(self.connection.dialect(), i)?;
+				Ok(self.connection.execute(statement.as_str(), &params)#wait? == 1)
+			}
+		}
+	}
+}
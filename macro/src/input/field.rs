@@ -1,44 +1,78 @@
 use crate::input::parse_utils;
+use crate::input::sql_type::{Storage, UuidVersion};
 use crate::SqlType;
+use convert_case::Casing;
+use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use syn::{DeriveInput, Fields};
 
+/// Attributes of a field read by the derive, any other attribute is ignored
+const ATTRIBUTES: [&str; 13] = [
+	"integer", "real", "boolean", "varchar", "text", "blob", "datetime", "date", "uuid", "json",
+	"enumeration", "column", "worm",
+];
+
 pub struct Field<'a> {
-	pub field: &'a syn::Field,
 	pub ident: &'a syn::Ident,
-	pub ty: &'a syn::Type,
+	/// Name of the SQL column
+	pub column: String,
+	/// Type of the values stored in the column, i.e. `T` for an `Option<T>` field
+	pub value_ty: &'a syn::Type,
+	pub optional: bool,
 	pub sql_type: SqlType,
-	pub attribute: String,
 	pub nullable: Option<bool>,
 	pub primary_key: bool,
+	/// Whether the primary key is generated by the database on insert
+	pub auto: bool,
 	pub unique: bool,
-	pub size: Option<u64>,
+	/// Version of the uuid generated on insert in place of a nil id
+	pub generate: Option<UuidVersion>,
+	/// Whether the column of an enumeration is constrained to the values of its variants
+	pub check: bool,
 }
 
 impl<'a> Field<'a> {
-	pub fn from_derive_input(ast: &'a syn::DeriveInput) -> Vec<Field> {
+	/// Parse the persisted fields of the struct, along with the identifiers of the fields skipped
+	/// with `#[worm(skip)]`
+	pub fn from_derive_input(
+		ast: &'a syn::DeriveInput,
+		rename_all: Option<convert_case::Case>,
+	) -> (Vec<Field<'a>>, Vec<&'a syn::Ident>) {
 		let mut result = vec![];
+		let mut skipped = vec![];
 		if let Some(syn::Fields::Named(fields_named)) = Self::get_fields(ast) {
 			for field in fields_named.named.iter() {
-				if let Some(f) = Self::from_field(field) {
-					result.push(f)
-				};
+				match Self::from_field(field, rename_all) {
+					Some(f) => result.push(f),
+					None => skipped.extend(field.ident.as_ref()),
+				}
 			}
 		}
-		result
+		(result, skipped)
 	}
 	
-	fn from_field(field: &syn::Field) -> Option<Field> {
+	/// Parse a field from its worm attributes, returning `None` when the field is skipped
+	///
+	/// Attributes that don't belong to worm, like doc comments, are left alone. `#[worm(...)]`
+	/// accepts the same options as the typed attributes and infers the type of the column.
+	fn from_field(field: &syn::Field, rename_all: Option<convert_case::Case>) -> Option<Field<'_>> {
 		if let Some(ident) = &field.ident {
 			let mut attribute = String::from("");
+			let mut column = None;
 			let mut nullable = None;
 			let mut primary_key = false;
+			let mut auto = false;
 			let mut unique = false;
 			let mut size = None;
+			let mut storage = None;
+			let mut generate = None;
+			let mut check = false;
+			let mut class = None;
+			let mut skip = false;
 			
-			for attr in &field.attrs {
-				match attr.parse_meta().unwrap() {
-					syn::Meta::List(syn::MetaList { path, nested, .. }) => {
+			for attr in field.attrs.iter().filter(|attr| ATTRIBUTES.iter().any(|a| attr.path.is_ident(a))) {
+				match attr.parse_meta() {
+					Ok(syn::Meta::List(syn::MetaList { path, nested, .. })) => {
 						for meta in nested.iter() {
 							match meta {
 								syn::NestedMeta::Meta(syn::Meta::NameValue(
@@ -56,18 +90,35 @@ impl<'a> Field<'a> {
 											"primary" => {
 												primary_key = parse_utils::parse_bool_lit(lit)
 											}
+											"auto" => auto = parse_utils::parse_bool_lit(lit),
+											"column" => {
+												column = Some(parse_utils::parse_str_lit(lit))
+											}
+											"storage" => {
+												storage = Some(parse_utils::parse_storage_lit(lit))
+											}
+											"generate" => {
+												generate = Some(parse_utils::parse_uuid_version_lit(lit))
+											}
+											"check" => check = parse_utils::parse_bool_lit(lit),
+											"type" => class = Some(lit.clone()),
 											_ => {
 												abort!(attr, "Unknown attribute");
 											}
 										}
 									}
 								}
+								syn::NestedMeta::Meta(syn::Meta::Path(option))
+									if path.is_ident("worm") && option.is_ident("skip") =>
+								{
+									skip = true
+								}
 								_ => {
-									abort!(attr, "malformed attribute syntax");
+									abort!(meta, "malformed attribute syntax");
 								}
 							}
 						}
-						if let Some(i) = path.get_ident() {
+						if let Some(i) = path.get_ident().filter(|i| *i != "worm") {
 							attribute = i.to_string();
 						}
 					}
@@ -76,24 +127,111 @@ impl<'a> Field<'a> {
 					}
 				};
 			}
-			let sql_type = SqlType::from_type(&field.ty, &attribute, size);
+			if skip {
+				return None;
+			}
+			let inner_ty = Self::option_inner_type(&field.ty);
+			if inner_ty.is_some() {
+				if primary_key {
+					abort!(field.ty, "Primary key cannot be optional");
+				}
+				if nullable == Some(false) {
+					abort!(field.ty, "Optional field cannot be NOT NULL");
+				}
+				nullable = Some(true);
+			}
+			let value_ty = inner_ty.unwrap_or(&field.ty);
+			// `#[column(type = "...")]` nominates the storage class of a type implementing the
+			// conversions of worm, which can't be checked by the derive
+			let sql_type = match (attribute.as_str(), class) {
+				("column", Some(class)) if storage.is_none() => SqlType::from_class(&class, size),
+				("column", Some(_)) => abort!(field.ty, "Storage for type not supported"),
+				("column", None) => abort!(field.ty, "Missing type, e.g. #[column(type = \"text\")]"),
+				(_, Some(class)) => abort!(class, "The type is only supported by #[column(...)]"),
+				(_, None) => SqlType::from_type(value_ty, &attribute, size, storage),
+			};
+			if generate.is_some() && !(primary_key && sql_type.is_uuid()) {
+				abort!(field.ty, "Generated ids are only supported on a uuid primary key");
+			}
+			if auto && !(primary_key && sql_type.is_integer()) {
+				abort!(field.ty, "Autoincrement is only supported on an integer primary key");
+			}
+			if check && !sql_type.is_enumeration() {
+				abort!(field.ty, "A CHECK constraint is only supported on an enumeration");
+			}
+			let column = column.unwrap_or_else(|| match rename_all {
+				Some(case) => ident.to_string().to_case(case),
+				None => ident.to_string(),
+			});
 			
 			Some(Field {
-				field,
 				ident,
-				ty: &field.ty,
-				attribute,
+				column,
+				value_ty,
+				optional: inner_ty.is_some(),
 				sql_type,
 				nullable,
 				unique,
 				primary_key,
-				size,
+				auto,
+				generate,
+				check,
 			})
 		} else {
 			None
 		}
 	}
 	
+	/// Variant of the field in the generated `Column` enum
+	pub fn variant(&self) -> syn::Ident {
+		syn::Ident::new(
+			&self.ident.to_string().to_case(convert_case::Case::Pascal),
+			proc_macro2::Span::call_site(),
+		)
+	}
+	
+	/// Expression converting `value`, a reference to the field or to a value of the column, into
+	/// the `worm::Value` bound to a statement. The expression returns early with the error of a
+	/// failed conversion, within a function returning `worm::Result`.
+	pub fn bind_value(&self, value: TokenStream) -> TokenStream {
+		if self.sql_type.is_json() {
+			return quote::quote! { ::worm::JsonStorage::to_json_value(#value)? };
+		}
+		match self.sql_type.storage() {
+			Some(Storage::Unix) => quote::quote! { ::worm::UnixStorage::to_unix_value(#value) },
+			Some(Storage::Blob) => quote::quote! { ::worm::BlobStorage::to_blob_value(#value) },
+			_ => quote::quote! { ::worm::ToSqlValue::to_sql_value(#value) },
+		}
+	}
+	
+	/// Expression converting `value`, a `worm::Value` read from the column, into the field
+	pub fn read_value(&self, value: TokenStream) -> TokenStream {
+		if self.sql_type.is_json() {
+			return quote::quote! { ::worm::JsonStorage::from_json_value(#value) };
+		}
+		match self.sql_type.storage() {
+			Some(Storage::Unix) => quote::quote! { ::worm::UnixStorage::from_unix_value(#value) },
+			Some(Storage::Blob) => quote::quote! { ::worm::BlobStorage::from_blob_value(#value) },
+			_ => quote::quote! { ::worm::FromSqlValue::from_sql_value(#value) },
+		}
+	}
+	
+	/// Retrieve `T` when the type is `Option<T>`
+	fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+		if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+			let segment = path.segments.last()?;
+			if segment.ident != "Option" {
+				return None;
+			}
+			if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+				if let Some(syn::GenericArgument::Type(inner)) = arguments.args.first() {
+					return Some(inner);
+				}
+			}
+		}
+		None
+	}
+	
 	pub fn get_fields(ast: &DeriveInput) -> Option<&Fields> {
 		if let syn::Data::Struct(syn::DataStruct { fields, .. }) = &ast.data {
 			Some(fields)
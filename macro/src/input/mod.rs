@@ -3,44 +3,247 @@ use proc_macro_error::abort;
 use std::slice::Iter;
 
 pub mod field;
-mod parse_utils;
+pub mod parse_utils;
 pub mod sql_type;
 
 pub struct Input<'a> {
 	pub ast: &'a syn::DeriveInput,
 	fields: Vec<Field<'a>>,
+	/// Fields that aren't persisted, filled from `Default` on select
+	pub skipped: Vec<&'a syn::Ident>,
 	pub name: &'a syn::Ident,
 	pub name_table: syn::Ident,
+	prefix: String,
+	module: Option<syn::Ident>,
+	table_name: String,
+}
+
+/// Struct level `#[table(...)]` attributes
+struct TableAttributes {
+	prefix: String,
+	module: Option<syn::Ident>,
+	table_name: String,
+	rename_all: Option<convert_case::Case>,
 }
 
 impl<'a> Input<'a> {
-	pub fn from_syn(ast: &'a syn::DeriveInput) -> Input {
+	pub fn from_syn(ast: &'a syn::DeriveInput) -> Input<'a> {
 		let name = &ast.ident;
-		let name_table = syn::Ident::new(format!("{}Table", name).as_str(), name.span());
-		let fields = Field::from_derive_input(ast);
+		let TableAttributes {
+			prefix,
+			module,
+			table_name,
+			rename_all,
+		} = Self::parse_table_attributes(ast);
+		let name_table = syn::Ident::new(format!("{}Table", prefix).as_str(), name.span());
+		let (fields, skipped) = Field::from_derive_input(ast, rename_all);
 		
 		Input {
 			ast,
 			name,
 			name_table,
 			fields,
+			skipped,
+			prefix,
+			module,
+			table_name,
+		}
+	}
+	
+	/// Parse the struct level `#[table(...)]` attributes
+	///
+	/// `prefix` nominates the prefix of every generated type, and defaults to the struct name.
+	/// `module` generates the types inside a module of that name instead, in which case the
+	/// prefix defaults to nothing.
+	/// `name` and `schema` nominate the SQL table, which defaults to the struct name.
+	/// `rename_all` derives the column names from the field names with the given case.
+	fn parse_table_attributes(ast: &syn::DeriveInput) -> TableAttributes {
+		let mut prefix = None;
+		let mut module = None;
+		let mut name = None;
+		let mut schema = None;
+		let mut rename_all = None;
+		
+		for attr in ast.attrs.iter().filter(|attr| attr.path.is_ident("table")) {
+			match attr.parse_meta() {
+				Ok(syn::Meta::List(syn::MetaList { nested, .. })) => {
+					for meta in nested.iter() {
+						match meta {
+							syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+								path,
+								lit,
+								..
+							})) => match path.get_ident().map(|i| i.to_string()).as_deref() {
+								Some("prefix") => prefix = Some(parse_utils::parse_str_lit(lit)),
+								Some("module") => {
+									module = Some(syn::Ident::new(
+										&parse_utils::parse_str_lit(lit),
+										lit.span(),
+									))
+								}
+								Some("name") => name = Some(parse_utils::parse_str_lit(lit)),
+								Some("schema") => schema = Some(parse_utils::parse_str_lit(lit)),
+								Some("rename_all") => rename_all = Some(parse_utils::parse_case_lit(lit)),
+								_ => abort!(path, "Unknown attribute"),
+							},
+							_ => abort!(meta, "malformed attribute syntax"),
+						}
+					}
+				}
+				_ => abort!(attr, "malformed attribute syntax"),
+			}
+		}
+		
+		let prefix = match (prefix, &module) {
+			(Some(prefix), _) => prefix,
+			(None, Some(_)) => String::new(),
+			(None, None) => ast.ident.to_string(),
+		};
+		let name = name.unwrap_or_else(|| ast.ident.to_string());
+		let table_name = match schema {
+			Some(schema) => format!("{}.{}", schema, name),
+			None => name,
+		};
+		TableAttributes {
+			prefix,
+			module,
+			table_name,
+			rename_all,
 		}
 	}
 	
-	pub fn fields_iter(&self) -> Iter<Field> {
+	pub fn fields_iter(&self) -> Iter<'_, Field<'_>> {
 		self.fields.iter()
 	}
 	
-	pub fn fields(&self) -> &Vec<Field> {
+	pub fn fields(&self) -> &Vec<Field<'_>> {
 		&self.fields
 	}
 	
+	/// Values bound for the fields of `obj`, a reference to an instance of the struct
+	pub fn to_sql_values(&self, obj: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+		self.fields
+			.iter()
+			.map(|f| {
+				let ident = f.ident;
+				f.bind_value(quote::quote! { &#obj.#ident })
+			})
+			.collect()
+	}
+	
+	/// Fields marked `primary = true`, in declaration order
+	pub fn primary_keys(&self) -> Vec<&Field<'_>> {
+		self.fields.iter().filter(|f| f.primary_key).collect()
+	}
+	
+	/// Values bound for the primary key fields of `obj`, an instance of the struct or of its key
+	pub fn to_key_values(&self, obj: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+		self.primary_keys()
+			.into_iter()
+			.map(|f| {
+				let ident = f.ident;
+				f.bind_value(quote::quote! { &#obj.#ident })
+			})
+			.collect()
+	}
+	
+	/// Primary key declared with `auto = true`, generated by the database on insert
+	pub fn auto_key(&self) -> Option<&Field<'_>> {
+		self.fields.iter().find(|f| f.auto)
+	}
+	
+	/// Uuid primary key declared with `generate = ...`, given a new id on insert when nil
+	pub fn generated_key(&self) -> Option<&Field<'_>> {
+		self.fields.iter().find(|f| f.generate.is_some())
+	}
+	
+	/// Type returned by insert, the type of the generated key if any
+	pub fn id_type(&self) -> proc_macro2::TokenStream {
+		match self.auto_key().or_else(|| self.generated_key()) {
+			Some(field) => {
+				let ty = field.value_ty;
+				quote::quote! { #ty }
+			}
+			None => quote::quote! { () },
+		}
+	}
+	
+	/// Fields written by inserts and updates, i.e. every field but a generated key
+	pub fn written_fields(&self) -> impl Iterator<Item = &Field<'_>> {
+		self.fields.iter().filter(|f| !f.auto)
+	}
+	
+	/// Values bound for the written fields of `obj`, in the order of [`Input::written_fields`]
+	pub fn to_written_values(&self, obj: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+		self.written_fields()
+			.map(|f| {
+				let ident = f.ident;
+				f.bind_value(quote::quote! { &#obj.#ident })
+			})
+			.collect()
+	}
+	
+	/// Values bound by the insert of `obj`, generating an id for a nil `#[uuid(generate = ...)]` key
+	pub fn to_insert_values(&self, obj: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+		self.written_fields()
+			.map(|f| {
+				let ident = f.ident;
+				match &f.generate {
+					Some(version) => f.bind_value(quote::quote! { &#version.or_generate(&#obj.#ident) }),
+					None => f.bind_value(quote::quote! { &#obj.#ident }),
+				}
+			})
+			.collect()
+	}
+	
+	/// Values bound by `insert` of `obj`, binding the uuid key generated beforehand in `id`
+	pub fn to_insert_values_with_id(&self, obj: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+		self.written_fields()
+			.map(|f| {
+				let ident = f.ident;
+				match &f.generate {
+					Some(_) => f.bind_value(quote::quote! { &id }),
+					None => f.bind_value(quote::quote! { &#obj.#ident }),
+				}
+			})
+			.collect()
+	}
+	
+	/// Identifier of a type generated for this table, e.g. `PersonFilter` for `Filter`
+	pub fn ident(&self, name: &str) -> syn::Ident {
+		syn::Ident::new(format!("{}{}", self.prefix, name).as_str(), self.name.span())
+	}
+	
+	/// Visibility of the generated types, matching the visibility of the struct
+	pub fn vis(&self) -> proc_macro2::TokenStream {
+		match (&self.module, &self.ast.vis) {
+			(Some(_), syn::Visibility::Inherited) => quote::quote! { pub(super) },
+			(_, vis) => quote::quote! { #vis },
+		}
+	}
+	
+	/// Wrap the generated items in the module nominated by `#[table(module = "...")]`, if any
+	pub fn wrap_module(&self, items: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+		match &self.module {
+			Some(module) => {
+				let vis = &self.ast.vis;
+				quote::quote! {
+					#vis mod #module {
+						use super::*;
+						#items
+					}
+				}
+			}
+			None => items,
+		}
+	}
+	
 	pub fn validate(&'a self) {
 		// validate data type
 		match &self.ast.data {
 			syn::Data::Struct(_) => {}
 			syn::Data::Enum(_) => {
-				abort!(&self.ast, "worm::Table is not supported for enum");
+				abort!(&self.ast, "worm::Table is not supported for enum, derive worm::Column to store it in a column");
 			}
 			syn::Data::Union(_) => {
 				abort!(&self.ast, "worm::Table is not supported for union");
@@ -61,9 +264,14 @@ impl<'a> Input<'a> {
 				abort!(field, "worm::Table does not support unit");
 			}
 		}
+		
+		if self.auto_key().is_some() && self.primary_keys().len() > 1 {
+			abort!(&self.ast, "An autoincrement primary key cannot be part of a composite key");
+		}
 	}
 	
+	/// Name of the SQL table, qualified by its schema if any
 	pub fn get_table_name(&'a self) -> String {
-		format!("{}", self.name)
+		self.table_name.clone()
 	}
 }
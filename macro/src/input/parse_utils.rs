@@ -1,3 +1,4 @@
+use crate::input::sql_type::{Storage, UuidVersion};
 use proc_macro_error::abort;
 use syn::Lit;
 
@@ -41,3 +42,44 @@ pub fn parse_integer_lit(lit: &Lit) -> u64 {
 		}
 	}
 }
+
+pub fn parse_str_lit(lit: &Lit) -> String {
+	match lit {
+		Lit::Str(str) => str.value(),
+		_ => {
+			abort!(lit, "Literal cannot be parsed as string")
+		}
+	}
+}
+
+/// Parse the case of `rename_all`, named after the style it produces
+pub fn parse_case_lit(lit: &Lit) -> convert_case::Case {
+	match parse_str_lit(lit).as_str() {
+		"lowercase" => convert_case::Case::Flat,
+		"UPPERCASE" => convert_case::Case::UpperFlat,
+		"snake_case" => convert_case::Case::Snake,
+		"SCREAMING_SNAKE_CASE" => convert_case::Case::UpperSnake,
+		"camelCase" => convert_case::Case::Camel,
+		"PascalCase" => convert_case::Case::Pascal,
+		_ => abort!(lit, "Unknown case, expected one of lowercase, UPPERCASE, snake_case, SCREAMING_SNAKE_CASE, camelCase or PascalCase"),
+	}
+}
+
+/// Parse the `storage` of a date, date and time or uuid column
+pub fn parse_storage_lit(lit: &Lit) -> Storage {
+	match parse_str_lit(lit).as_str() {
+		"text" => Storage::Text,
+		"unix" => Storage::Unix,
+		"blob" => Storage::Blob,
+		_ => abort!(lit, "Unknown storage, expected one of text, unix or blob"),
+	}
+}
+
+/// Parse the version of the uuid generated on insert
+pub fn parse_uuid_version_lit(lit: &Lit) -> UuidVersion {
+	match parse_str_lit(lit).as_str() {
+		"v4" => UuidVersion::V4,
+		"v7" => UuidVersion::V7,
+		_ => abort!(lit, "Unknown uuid version, expected v4 or v7"),
+	}
+}
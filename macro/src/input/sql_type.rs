@@ -1,14 +1,85 @@
+use crate::dialect::Dialect;
+use crate::input::parse_utils;
 use proc_macro_error::abort;
 use std::borrow::Cow;
 
+/// Primitive integer types stored in an INTEGER column
+const INTEGERS: [&str; 10] = ["i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize"];
+
 pub enum SqlType {
 	Integer,
+	Real,
+	Boolean,
 	Varchar(u64),
 	Text,
+	Blob,
+	DateTime(Storage),
+	Date(Storage),
+	Uuid(Storage),
+	Json,
+	/// Fieldless enum implementing `worm::Column`, whose SQL type is only known at run time
+	Enumeration,
+}
+
+/// Storage of the `#[datetime]`, `#[date]` and `#[uuid]` columns
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Storage {
+	/// ISO-8601 text in UTC for dates, hyphenated text for uuids
+	Text,
+	/// Seconds since the unix epoch, dates only
+	Unix,
+	/// The 16 bytes of a uuid
+	Blob,
+}
+
+/// Version of the uuid generated on insert by `#[uuid(generate = ...)]`
+#[derive(Clone, Copy)]
+pub enum UuidVersion {
+	V4,
+	V7,
+}
+
+impl quote::ToTokens for UuidVersion {
+	fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+		tokens.extend(match self {
+			UuidVersion::V4 => quote::quote! { ::worm::UuidVersion::V4 },
+			UuidVersion::V7 => quote::quote! { ::worm::UuidVersion::V7 },
+		})
+	}
 }
 
 impl SqlType {
-	pub fn from_type(ty: &syn::Type, attr: &str, size: Option<u64>) -> SqlType {
+	pub fn from_type(ty: &syn::Type, attr: &str, size: Option<u64>, storage: Option<Storage>) -> SqlType {
+		match (Self::from_attribute(ty, attr, size), storage) {
+			(sql_type, None) => sql_type,
+			(SqlType::DateTime(_), Some(storage @ (Storage::Text | Storage::Unix))) => SqlType::DateTime(storage),
+			(SqlType::Date(_), Some(storage @ (Storage::Text | Storage::Unix))) => SqlType::Date(storage),
+			(SqlType::Uuid(_), Some(storage @ (Storage::Text | Storage::Blob))) => SqlType::Uuid(storage),
+			(_, Some(_)) => abort!(ty, "Storage for type not supported"),
+		}
+	}
+	
+	/// SQL type of a `#[column(type = "...")]` field, whose Rust type implements the conversions
+	pub fn from_class(class: &syn::Lit, size: Option<u64>) -> SqlType {
+		let sql_type = match parse_utils::parse_str_lit(class).as_str() {
+			"varchar" => match size {
+				Some(size) => return SqlType::Varchar(size),
+				None => abort!(class, "Unknown size"),
+			},
+			"integer" => SqlType::Integer,
+			"real" => SqlType::Real,
+			"boolean" => SqlType::Boolean,
+			"text" => SqlType::Text,
+			"blob" => SqlType::Blob,
+			_ => abort!(class, "Unknown type, expected one of integer, real, boolean, varchar, text or blob"),
+		};
+		if size.is_some() {
+			abort!(class, "Size for type not supported");
+		}
+		sql_type
+	}
+	
+	fn from_attribute(ty: &syn::Type, attr: &str, size: Option<u64>) -> SqlType {
 		if let syn::Type::Path(syn::TypePath { path, .. }) = ty {
 			match (ty, attr, size) {
 				(_, "varchar", Some(size)) if path.is_ident("String") => SqlType::Varchar(size),
@@ -17,10 +88,29 @@ impl SqlType {
 				(_, "text", Some(_)) => abort!(ty, "Size for type not supported"),
 				(_, "text", None) => SqlType::Text,
 				(_, "integer", Some(_)) => abort!(path, "Size for type not supported"),
-				(_, "integer", None) if path.is_ident("u64") => SqlType::Integer,
-				(_, "integer", None) if path.is_ident("u32") => SqlType::Integer,
-				(_, "integer", None) if path.is_ident("i64") => SqlType::Integer,
-				(_, "integer", None) if path.is_ident("i32") => SqlType::Integer,
+				(_, "integer", None) if INTEGERS.iter().any(|i| path.is_ident(i)) => SqlType::Integer,
+				(_, "real", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "real", None) if path.is_ident("f32") || path.is_ident("f64") => SqlType::Real,
+				(_, "boolean", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "boolean", None) if path.is_ident("bool") => SqlType::Boolean,
+				(_, "blob", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "blob", None) if matches!(Self::infer(ty), Some(SqlType::Blob)) => SqlType::Blob,
+				(_, "datetime" | "date", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "datetime", None) if matches!(Self::infer(ty), Some(SqlType::DateTime(_))) => {
+					SqlType::DateTime(Storage::Text)
+				}
+				(_, "date", None) if Self::is_date(path) => SqlType::Date(Storage::Text),
+				(_, "uuid", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "uuid", None) if matches!(Self::infer(ty), Some(SqlType::Uuid(_))) => {
+					SqlType::Uuid(Storage::Text)
+				}
+				(_, "json", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "json", None) => SqlType::Json,
+				(_, "enumeration", Some(_)) => abort!(path, "Size for type not supported"),
+				(_, "enumeration", None) => SqlType::Enumeration,
+				(_, "", _) => Self::infer(ty).unwrap_or_else(|| {
+					abort!(ty, "Unable to infer the SQL type, add an attribute such as #[integer()] or #[text()]")
+				}),
 				_ => abort!(ty, "Unknown type"),
 			}
 		} else {
@@ -28,11 +118,110 @@ impl SqlType {
 		}
 	}
 	
-	pub fn to_string(&self) -> Cow<str> {
+	/// SQL type of a field without attribute, inferred from its Rust type
+	fn infer(ty: &syn::Type) -> Option<SqlType> {
+		match ty {
+			syn::Type::Path(syn::TypePath { qself: None, path }) => {
+				let segment = path.segments.last()?;
+				match (segment.ident.to_string().as_str(), &segment.arguments) {
+					(ident, syn::PathArguments::None) if INTEGERS.contains(&ident) => Some(SqlType::Integer),
+					("bool", syn::PathArguments::None) => Some(SqlType::Boolean),
+					("f32" | "f64", syn::PathArguments::None) => Some(SqlType::Real),
+					("String", syn::PathArguments::None) => Some(SqlType::Text),
+					("DateTime", syn::PathArguments::AngleBracketed(_))
+					| ("OffsetDateTime", syn::PathArguments::None) => Some(SqlType::DateTime(Storage::Text)),
+					("NaiveDate", syn::PathArguments::None) => Some(SqlType::Date(Storage::Text)),
+					("Uuid", syn::PathArguments::None) => Some(SqlType::Uuid(Storage::Text)),
+					("Vec", syn::PathArguments::AngleBracketed(arguments)) => {
+						match arguments.args.first()? {
+							syn::GenericArgument::Type(syn::Type::Path(inner))
+								if inner.path.is_ident("u8") =>
+							{
+								Some(SqlType::Blob)
+							}
+							_ => None,
+						}
+					}
+					_ => None,
+				}
+			}
+			_ => None,
+		}
+	}
+	
+	/// `chrono::NaiveDate` or `time::Date`, the latter is only recognised with `#[date]`
+	fn is_date(path: &syn::Path) -> bool {
+		path.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "NaiveDate" || segment.ident == "Date")
+	}
+	
+	/// Storage of a `#[datetime]`, `#[date]` or `#[uuid]` column
+	pub fn storage(&self) -> Option<Storage> {
 		match self {
-			SqlType::Integer => Cow::from("INTEGER"),
-			SqlType::Text => Cow::from("TEXT"),
+			SqlType::DateTime(storage) | SqlType::Date(storage) | SqlType::Uuid(storage) => Some(*storage),
+			_ => None,
+		}
+	}
+	
+	/// Whether the values of the type can be compared with `<` and `>` in filters
+	pub fn is_ordered(&self) -> bool {
+		!matches!(
+			self,
+			SqlType::Boolean | SqlType::Blob | SqlType::Uuid(_) | SqlType::Json | SqlType::Enumeration
+		)
+	}
+	
+	pub fn is_integer(&self) -> bool {
+		matches!(self, SqlType::Integer)
+	}
+	
+	pub fn is_boolean(&self) -> bool {
+		matches!(self, SqlType::Boolean)
+	}
+	
+	pub fn is_uuid(&self) -> bool {
+		matches!(self, SqlType::Uuid(_))
+	}
+	
+	pub fn is_json(&self) -> bool {
+		matches!(self, SqlType::Json)
+	}
+	
+	pub fn is_enumeration(&self) -> bool {
+		matches!(self, SqlType::Enumeration)
+	}
+	
+	pub fn to_string(&self, dialect: Dialect) -> Cow<'_, str> {
+		match self {
+			SqlType::Integer
+			| SqlType::DateTime(Storage::Unix)
+			| SqlType::Date(Storage::Unix) => match dialect {
+				Dialect::Sqlite => Cow::from("INTEGER"),
+				Dialect::Postgres => Cow::from("BIGINT"),
+			},
+			SqlType::Real => match dialect {
+				Dialect::Sqlite => Cow::from("REAL"),
+				Dialect::Postgres => Cow::from("DOUBLE PRECISION"),
+			},
+			SqlType::Boolean => Cow::from("BOOLEAN"),
+			SqlType::Text
+			| SqlType::DateTime(Storage::Text)
+			| SqlType::Date(Storage::Text)
+			| SqlType::Uuid(Storage::Text) => Cow::from("TEXT"),
+			SqlType::Blob | SqlType::Uuid(Storage::Blob) => match dialect {
+				Dialect::Sqlite => Cow::from("BLOB"),
+				Dialect::Postgres => Cow::from("BYTEA"),
+			},
 			SqlType::Varchar(size) => Cow::from(format!("VARCHAR({})", size)),
+			SqlType::Json => match dialect {
+				Dialect::Sqlite => Cow::from("TEXT"),
+				Dialect::Postgres => Cow::from("JSONB"),
+			},
+			SqlType::DateTime(Storage::Blob) | SqlType::Date(Storage::Blob) | SqlType::Uuid(Storage::Unix) => {
+				unreachable!("The storage is checked against the type when parsing the field")
+			}
+			SqlType::Enumeration => unreachable!("The type of an enumeration is rendered at run time"),
 		}
 	}
 }
@@ -1,9 +1,17 @@
 extern crate core;
 
+mod dialect;
+mod execution;
+mod impl_changeset;
 mod impl_filter;
 mod impl_filter_wrapper;
+mod impl_key;
+mod impl_order_by;
 mod impl_select;
+mod impl_upsert;
 mod input;
+mod worm_async_table;
+mod worm_column;
 mod worm_table;
 
 use input::sql_type::SqlType;
@@ -13,9 +21,17 @@ use syn::parse_macro_input;
 use syn::DeriveInput;
 
 /// Macro definition for worm_table
-#[proc_macro_derive(Table, attributes(integer, varchar, text))]
+#[proc_macro_derive(Table, attributes(table, worm, integer, real, boolean, varchar, text, blob, datetime, date, uuid, json, enumeration, column))]
 #[proc_macro_error]
 pub fn worm_table(input: TokenStream) -> proc_macro::TokenStream {
 	let derive_input = parse_macro_input!(input as DeriveInput);
 	worm_table::derive(&derive_input).into()
 }
+
+/// Macro definition for worm_column, storing a fieldless enum in a column
+#[proc_macro_derive(Column, attributes(column))]
+#[proc_macro_error]
+pub fn worm_column(input: TokenStream) -> proc_macro::TokenStream {
+	let derive_input = parse_macro_input!(input as DeriveInput);
+	worm_column::derive(&derive_input).into()
+}
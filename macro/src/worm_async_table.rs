@@ -0,0 +1,58 @@
+use crate::execution::Execution;
+use crate::input::Input;
+use crate::worm_table::{table_methods, table_strict};
+
+/// Generate the asynchronous table running the statement builders through an sqlx pool
+pub fn impl_async_table(input: &Input) -> proc_macro2::TokenStream {
+	if !cfg!(feature = "sqlx") {
+		return quote::quote! {};
+	}
+	
+	let name = input.name;
+	let async_table = input.ident("AsyncTable");
+	let vis = input.vis();
+	let methods = table_methods(input, Execution::Async);
+	let strict = table_strict(input, Execution::Async);
+	
+	let doc = format!(
+		r#"
+The struct {} wraps around an sqlx connection pool - specified as part of the constructor -
+and manages the asynchronous interaction between the software and the database
+"#,
+		async_table
+	);
+	let doc_sqlite = format!(
+		"Construct an asynchronous SQL connector to manipulate struct of type {} to an SQLite database using an sqlx pool",
+		name
+	);
+	let doc_postgres = format!(
+		"Construct an asynchronous SQL connector to manipulate struct of type {} to a PostgreSQL database using an sqlx pool",
+		name
+	);
+	
+	quote::quote! {
+		#[doc = #doc]
+		#vis struct #async_table<'a> {
+			connection: ::worm::AsyncConnection<'a>,
+			strict: bool,
+		}
+		
+		impl #name {
+			#[doc = #doc_sqlite]
+			pub fn from_sqlx_sqlite<'a>(pool: &'a ::worm::driver::sqlx::SqlitePool) -> #async_table<'a> {
+				#async_table { connection: ::worm::AsyncConnection::SqlxSqlite(pool), strict: false }
+			}
+			
+			#[doc = #doc_postgres]
+			pub fn from_sqlx_postgres<'a>(pool: &'a ::worm::driver::sqlx::PgPool) -> #async_table<'a> {
+				#async_table { connection: ::worm::AsyncConnection::SqlxPostgres(pool), strict: false }
+			}
+		}
+		
+		impl<'a> #async_table<'a> {
+			#strict
+			
+			#( #methods )*
+		}
+	}
+}
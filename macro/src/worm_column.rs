@@ -0,0 +1,158 @@
+use crate::input::parse_utils;
+use convert_case::Casing;
+use proc_macro_error::abort;
+
+/// Storage of the variants of an enum
+enum EnumStorage {
+	/// Name of the variant in a TEXT column
+	Text,
+	/// Discriminant of the variant in an INTEGER column
+	Integer,
+}
+
+/// Implement `worm::Column` and the value conversions for a fieldless enum
+///
+/// `#[column(storage = "text")]`, the default, stores the name of the variant, which follows
+/// `rename_all` unless nominated by `#[column(name = "...")]` on the variant.
+/// `#[column(storage = "integer")]` stores its discriminant.
+pub fn derive(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+	let name = &ast.ident;
+	let variants = match &ast.data {
+		syn::Data::Enum(data) => &data.variants,
+		_ => abort!(ast, "worm::Column is only supported for enum"),
+	};
+	if variants.is_empty() {
+		abort!(ast, "worm::Column does not support enum with no variants");
+	}
+	if let Some(variant) = variants.iter().find(|v| !matches!(v.fields, syn::Fields::Unit)) {
+		abort!(variant, "worm::Column only supports variants without fields");
+	}
+	
+	let mut storage = EnumStorage::Text;
+	let mut rename_all = None;
+	for (path, lit) in column_attributes(&ast.attrs) {
+		match path.get_ident().map(|i| i.to_string()).as_deref() {
+			Some("storage") => {
+				storage = match parse_utils::parse_str_lit(&lit).as_str() {
+					"text" => EnumStorage::Text,
+					"integer" => EnumStorage::Integer,
+					_ => abort!(lit, "Unknown storage, expected text or integer"),
+				}
+			}
+			Some("rename_all") => rename_all = Some(parse_utils::parse_case_lit(&lit)),
+			_ => abort!(path, "Unknown attribute"),
+		}
+	}
+	
+	let idents: Vec<&syn::Ident> = variants.iter().map(|v| &v.ident).collect();
+	let (to_values, from_values, sql_type, literals) = match storage {
+		EnumStorage::Text => {
+			let names: Vec<String> = variants.iter().map(|v| variant_name(v, rename_all)).collect();
+			let literals: Vec<String> = names.iter().map(|n| format!("'{}'", n.replace('\'', "''"))).collect();
+			(
+				quote::quote! { #( #name::#idents => ::worm::Value::Text(String::from(#names)), )* },
+				quote::quote! {
+					::worm::Value::Text(v) => match v.as_str() {
+						#( #names => Ok(#name::#idents), )*
+						_ => Err(v),
+					}
+				},
+				quote::quote! { "TEXT" },
+				quote::quote! { vec![ #( String::from(#literals) ),* ] },
+			)
+		}
+		EnumStorage::Integer => {
+			if let Some(variant) = variants.iter().find(|v| has_name(v)) {
+				abort!(variant, "A name is only supported with storage = \"text\"");
+			}
+			(
+				quote::quote! { #( #name::#idents => ::worm::Value::Integer(#name::#idents as i64), )* },
+				quote::quote! {
+					::worm::Value::Integer(v) => match v {
+						#( v if v == #name::#idents as i64 => Ok(#name::#idents), )*
+						_ => Err(v),
+					}
+				},
+				quote::quote! {
+					match dialect {
+						::worm::Dialect::Sqlite => "INTEGER",
+						::worm::Dialect::Postgres => "BIGINT",
+					}
+				},
+				quote::quote! { vec![ #( (#name::#idents as i64).to_string() ),* ] },
+			)
+		}
+	};
+	let unexpected = format!("Unable to convert {{:?}} to {}", name);
+	let unknown = format!("{{:?}} is not a variant of {}", name);
+	
+	quote::quote! {
+		impl ::worm::ToSqlValue for #name {
+			fn to_sql_value(&self) -> ::worm::Value {
+				match self {
+					#to_values
+				}
+			}
+		}
+		
+		impl ::worm::FromSqlValue for #name {
+			fn from_sql_value(value: ::worm::Value) -> ::worm::Result<Self> {
+				let variant = match value {
+					#from_values,
+					value => return Err(::worm::Error::Conversion(format!(#unexpected, value))),
+				};
+				variant.map_err(|v| ::worm::Error::Conversion(format!(#unknown, v)))
+			}
+		}
+		
+		impl ::worm::Column for #name {
+			fn sql_type(dialect: ::worm::Dialect) -> &'static str {
+				#sql_type
+			}
+			
+			fn sql_literals() -> Vec<String> {
+				#literals
+			}
+		}
+	}
+}
+
+/// Name-value pairs of the `#[column(...)]` attributes
+fn column_attributes(attrs: &[syn::Attribute]) -> Vec<(syn::Path, syn::Lit)> {
+	let mut result = vec![];
+	for attr in attrs.iter().filter(|attr| attr.path.is_ident("column")) {
+		match attr.parse_meta() {
+			Ok(syn::Meta::List(syn::MetaList { nested, .. })) => {
+				for meta in nested.into_iter() {
+					match meta {
+						syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. })) => {
+							result.push((path, lit))
+						}
+						meta => abort!(meta, "malformed attribute syntax"),
+					}
+				}
+			}
+			_ => abort!(attr, "malformed attribute syntax"),
+		}
+	}
+	result
+}
+
+fn has_name(variant: &syn::Variant) -> bool {
+	column_attributes(&variant.attrs).iter().any(|(path, _)| path.is_ident("name"))
+}
+
+/// Name stored for a variant with `storage = "text"`
+fn variant_name(variant: &syn::Variant, rename_all: Option<convert_case::Case>) -> String {
+	let mut name = None;
+	for (path, lit) in column_attributes(&variant.attrs) {
+		match path.get_ident().map(|i| i.to_string()).as_deref() {
+			Some("name") => name = Some(parse_utils::parse_str_lit(&lit)),
+			_ => abort!(path, "Unknown attribute"),
+		}
+	}
+	name.unwrap_or_else(|| match rename_all {
+		Some(case) => variant.ident.to_string().to_case(case),
+		None => variant.ident.to_string(),
+	})
+}
@@ -1,68 +1,216 @@
+use crate::dialect::{escape_format, match_dialect, match_dialect_format, Dialect};
+use crate::execution::Execution;
+use crate::impl_changeset::impl_changeset;
 use crate::impl_filter::impl_filter;
 use crate::impl_filter_wrapper::impl_filter_wrapper;
+use crate::impl_key::impl_key;
+use crate::impl_order_by::impl_order_by;
+use crate::impl_upsert::impl_upsert;
+use crate::input::field::Field;
 use crate::input::Input;
+use crate::worm_async_table::impl_async_table;
 use crate::SqlType;
 
 pub fn derive(derive_input: &syn::DeriveInput) -> proc_macro2::TokenStream {
 	let input = Input::from_syn(derive_input);
 	input.validate();
 	
-	let impl_enum = impl_enum(&input);
 	let impl_create_table = impl_create_table(&input);
 	let impl_delete_table = impl_delete_table(&input);
 	let impl_insert = impl_insert(&input);
 	let impl_update = impl_update(&input);
 	let filter_struct = impl_filter(&input);
+	let order_by_enum = impl_order_by(&input);
 	let select_struct = crate::impl_select::impl_select(&input);
-	let impl_select_one_wrapper = impl_filter_wrapper(&input, "SelectOne", &input.name.span());
-	let impl_count_wrapper = impl_filter_wrapper(&input, "Count", &input.name.span());
-	let impl_delete_wrapper = impl_filter_wrapper(&input, "Delete", &input.name.span());
+	let impl_select_one_wrapper = impl_filter_wrapper(&input, "SelectOne");
+	let impl_count_wrapper = impl_filter_wrapper(&input, "Count");
+	let impl_delete_wrapper = impl_filter_wrapper(&input, "Delete");
 	let impl_struct_table = impl_struct_table(&input);
 	let impl_constructor = impl_constructor(&input);
+	let impl_async_table = impl_async_table(&input);
+	let impl_key = impl_key(&input);
+	let impl_upsert = impl_upsert(&input);
+	let impl_changeset = impl_changeset(&input);
 	let impl_select_all = impl_select_all(&input);
-	let impl_select_one = impl_select_one(&input);
 	let impl_select = impl_select(&input);
 	let impl_count_all = impl_count_all(&input);
 	let impl_count = impl_count(&input);
 	let impl_delete_all = impl_delete_all(&input);
 	let impl_delete = impl_delete(&input);
+	let impl_table = impl_table(&input);
 	
-	quote::quote! {
+	input.wrap_module(quote::quote! {
 		#impl_create_table
 		#impl_delete_table
 		#impl_insert
 		#impl_update
-		#impl_enum
 		#impl_count_wrapper
 		#impl_count
 		#impl_count_all
 		#impl_constructor
 		#impl_struct_table
+		#impl_table
+		#impl_async_table
+		#impl_key
+		#impl_upsert
+		#impl_changeset
 		#filter_struct
+		#order_by_enum
 		#select_struct
 		#impl_select_one_wrapper
-		#impl_select_one
 		#impl_select
 		#impl_select_all
 		#impl_delete_wrapper
 		#impl_delete
 		#impl_delete_all
+	})
+}
+
+/// Methods of `worm::Table`, implemented by the synchronous table and inherent to the
+/// asynchronous table
+pub fn table_methods(input: &Input, exec: Execution) -> Vec<proc_macro2::TokenStream> {
+	vec![
+		table_create_table(input, exec),
+		table_delete_table(input, exec),
+		table_insert(input, exec),
+		table_update(input, exec),
+		table_select(input, exec),
+		table_select_one(input, exec),
+		table_select_all(input, exec),
+		table_count(input, exec),
+		table_count_all(input, exec),
+		table_delete(input, exec),
+		table_delete_all(input, exec),
+	]
+}
+
+/// Strict mode of a table, checking the number of rows updated by the update of a single row
+pub fn table_strict(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let table = exec.table(input);
+	
+	quote::quote! {
+		/// Fail the updates of a single row - `update_by_id`, `update_to`, `update_to_by` and `update_by_key` -
+		/// with `worm::Error::NotFound` when they update no row, and with
+		/// `worm::Error::UnexpectedRowCount` when they update several rows
+		pub fn strict(self) -> #table<'a> {
+			#table { strict: true, ..self }
+		}
+		
+		/// Number of rows updated by the update of a single row, checked in strict mode
+		fn updated_row_count(&self, count: usize) -> ::worm::Result<usize> {
+			match count {
+				0 if self.strict => Err(::worm::Error::NotFound),
+				1 => Ok(count),
+				_ if self.strict => Err(::worm::Error::UnexpectedRowCount { expected: 1, actual: count }),
+				_ => Ok(count),
+			}
+		}
 	}
 }
 
-fn impl_enum(_input: &Input) -> proc_macro2::TokenStream {
-	let doc = "This enum provides an identification of the different SQL wrapper supported";
+/// Implement `worm::Table` for the table struct, with inherent methods forwarding to the trait so
+/// that callers don't need the trait in scope
+fn impl_table(input: &Input) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let name_table = &input.name_table;
+	let filter = input.ident("Filter");
+	let select = input.ident("Select");
+	let select_one = input.ident("SelectOne");
+	let count = input.ident("Count");
+	let delete = input.ident("Delete");
+	let id = input.id_type();
+	let methods = table_methods(input, Execution::Sync);
+	let strict = table_strict(input, Execution::Sync);
 	
 	quote::quote! {
-	  #[doc = #doc]
-	  enum SqlConnection<'a> {
-			Rusqlite(&'a rusqlite::Connection),
-	  }
+		impl<'a> ::worm::Table for #name_table<'a> {
+			type Row = #name;
+			type Id = #id;
+			type Filter = #filter;
+			type Select = #select;
+			type SelectOne = #select_one;
+			type Count = #count;
+			type Delete = #delete;
+			
+			#( #methods )*
+		}
+		
+		impl<'a> #name_table<'a> {
+			#strict
+			
+			pub fn create_table(&self) -> ::worm::Result<()> {
+				::worm::Table::create_table(self)
+			}
+			
+			pub fn delete_table(&self) -> ::worm::Result<()> {
+				::worm::Table::delete_table(self)
+			}
+			
+			pub fn insert(&self, i: &#name) -> ::worm::Result<#id> {
+				::worm::Table::insert(self, i)
+			}
+			
+			pub fn insert_returning(&self, i: &#name) -> ::worm::Result<#name> {
+				::worm::Table::insert_returning(self, i)
+			}
+			
+			pub fn insert_many(&self, rows: &[#name]) -> ::worm::Result<usize> {
+				::worm::Table::insert_many(self, rows)
+			}
+			
+			pub fn insert_iter<'r, I: IntoIterator<Item = &'r #name>>(&self, rows: I) -> ::worm::Result<usize>
+			where
+				#name: 'r,
+			{
+				::worm::Table::insert_iter(self, rows)
+			}
+			
+			pub fn update_by_id(&self, obj: &#name) -> ::worm::Result<usize> {
+				::worm::Table::update_by_id(self, obj)
+			}
+			
+			pub fn update_to(&self, from: &#name, to: &#name) -> ::worm::Result<usize> {
+				::worm::Table::update_to(self, from, to)
+			}
+			
+			pub fn select(&self, select: #select) -> ::worm::Result<Vec<#name>> {
+				::worm::Table::select(self, select)
+			}
+			
+			pub fn select_one(&self, select_one: #select_one) -> ::worm::Result<Option<#name>> {
+				::worm::Table::select_one(self, select_one)
+			}
+			
+			pub fn select_all(&self) -> ::worm::Result<Vec<#name>> {
+				::worm::Table::select_all(self)
+			}
+			
+			pub fn select_page(&self, select: #select, page: usize, per_page: usize) -> ::worm::Result<::worm::Page<#name>> {
+				::worm::Table::select_page(self, select, page, per_page)
+			}
+			
+			pub fn count(&self, count: #count) -> ::worm::Result<usize> {
+				::worm::Table::count(self, count)
+			}
+			
+			pub fn count_all(&self) -> ::worm::Result<usize> {
+				::worm::Table::count_all(self)
+			}
+			
+			pub fn delete(&self, delete: #delete) -> ::worm::Result<()> {
+				::worm::Table::delete(self, delete)
+			}
+			
+			pub fn delete_all(&self) -> ::worm::Result<()> {
+				::worm::Table::delete_all(self)
+			}
+		}
 	}
 }
 
 fn impl_struct_table(input: &Input) -> proc_macro2::TokenStream {
 	let name_table = &input.name_table;
+	let vis = input.vis();
 	let doc = format!(
 		r#"
 The struct {} wraps around the connection to the SQL database - specified as part of the constructor -
@@ -73,8 +221,9 @@ and manages the interaction between the software and the database
 	
 	quote::quote! {
 		#[doc = #doc]
-		pub struct #name_table<'a> {
-			connection: SqlConnection<'a>,
+		#vis struct #name_table<'a> {
+			connection: ::worm::Connection<'a>,
+			strict: bool,
 		}
 	}
 }
@@ -82,150 +231,300 @@ and manages the interaction between the software and the database
 fn impl_constructor(input: &Input) -> proc_macro2::TokenStream {
 	let name = input.name;
 	let name_table = &input.name_table;
-	let doc = format!(
-		r#"
+	
+	let mut constructors = vec![];
+	if cfg!(feature = "rusqlite") {
+		let doc = format!(
+			r#"
 Construct a SQL connector to manipulate struct of type {} to an SQLite database using the rusqlite wrapper
 "#,
-		input.name
-	);
+			input.name
+		);
+		constructors.push(quote::quote! {
+			#[doc = #doc]
+			pub fn from_rusqlite<'a>(conn: &'a ::worm::driver::rusqlite::Connection) -> #name_table<'a> {
+				#name_table { connection: ::worm::Connection::Rusqlite(conn), strict: false }
+			}
+		});
+	}
+	if cfg!(feature = "postgres") {
+		let doc = format!(
+			r#"
+Construct a SQL connector to manipulate struct of type {} to a PostgreSQL database using the postgres wrapper
+"#,
+			input.name
+		);
+		constructors.push(quote::quote! {
+			#[doc = #doc]
+			pub fn from_postgres<'a>(client: &'a mut ::worm::driver::postgres::Client) -> #name_table<'a> {
+				#name_table { connection: ::worm::Connection::Postgres(std::cell::RefCell::new(client)), strict: false }
+			}
+		});
+	}
 	
 	quote::quote! {
-		#[doc = #doc]
 		impl #name {
-			pub fn from_rusqlite<'a>(conn: &'a rusqlite::Connection) -> #name_table<'a> {
-				#name_table { connection: SqlConnection::Rusqlite(conn) }
-			}
+			#( #constructors )*
 		}
 	}
 }
 
 fn impl_delete_all(input: &Input) -> proc_macro2::TokenStream {
 	let name = &input.name;
-	let name_table = &input.name_table;
+	let statement = format!("DELETE FROM {}", input.get_table_name());
 	
 	quote::quote! {
 		impl #name {
-			pub fn delete_all() -> String {
-				#name::delete(DeleteBuilder::default().build())
+			pub fn delete_all() -> (String, Vec<::worm::Value>) {
+				(String::from(#statement), vec![])
 			}
 		}
-		
-		impl<'a> #name_table<'a> {
-			pub fn delete_all(&self) -> Result<(), Box<dyn std::error::Error>> {
-				self.delete(DeleteBuilder::default().build())
-			}
+	}
+}
+
+fn table_delete_all(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let delete_builder = input.ident("DeleteBuilder");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn delete_all(&self) -> ::worm::Result<()> {
+			self.delete(#delete_builder::default().build())#wait
 		}
 	}
 }
 
 fn impl_delete(input: &Input) -> proc_macro2::TokenStream {
 	let name = &input.name;
-	let name_table = &input.name_table;
+	let filter = input.ident("Filter");
+	let delete = input.ident("Delete");
 	let statement = format!("DELETE FROM {}", input.get_table_name());
 	
 	quote::quote! {
 		impl #name {
-			pub fn delete(delete: Delete) -> String {
+			pub fn delete(delete: #delete) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				#name::delete_for(::worm::Dialect::Sqlite, delete)
+			}
+			
+			pub fn delete_for(dialect: ::worm::Dialect, delete: #delete) -> ::worm::Result<(String, Vec<::worm::Value>)> {
 				let mut statement = #statement.to_string();
+				let mut params = vec![];
 				if let Some(filter) = delete.filter {
-					statement += format!(" WHERE {}", Filter::to_condition(&filter)).as_str();
+					statement += format!(" WHERE {}", #filter::to_condition(&filter, dialect, &mut params)?).as_str();
 				}
-				statement
+				Ok((statement, params))
 			}
 		}
-		
-		impl<'a> #name_table<'a> {
-			pub fn delete(&self, delete: Delete) -> Result<(), Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						let statement = #name::delete(delete);
-						conn.execute(statement.as_str(), ())?;
-						Ok(())
-					}
-				}
-			}
+	}
+}
+
+fn table_delete(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = &input.name;
+	let delete = input.ident("Delete");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn delete(&self, delete: #delete) -> ::worm::Result<()> {
+			let (statement, params) = #name::delete_for(self.connection.dialect(), delete)?;
+			self.connection.execute(statement.as_str(), &params)#wait?;
+			Ok(())
 		}
 	}
 }
 
 fn impl_update(input: &Input) -> proc_macro2::TokenStream {
 	let name = input.name;
-	let name_table = &input.name_table;
-	let fields_named = &input.fields();
 	
-	let statement_to = format!(
-		"UPDATE {} SET {} WHERE {}",
-		input.get_table_name(),
-		collect_join(
-			fields_named.iter().enumerate().map(|(i, f)| format!(
-				"{} = ?{}",
-				f.ident,
-				i + 1 + fields_named.len()
-			)),
-			", "
-		),
-		collect_join(
-			fields_named
-				.iter()
-				.enumerate()
-				.map(|(i, f)| format!("{} = ?{}", f.ident, i + 1)),
-			" AND "
+	// The values identifying the row come first, numbered in the order of the matched fields
+	let update = |dialect: Dialect, matched: &[&Field], equal: &str| {
+		format!(
+			"UPDATE {} SET {} WHERE {}",
+			input.get_table_name(),
+			collect_join(
+				input.written_fields().enumerate().map(|(i, f)| format!(
+					"{} = {}",
+					f.column,
+					dialect.placeholder(i + 1 + matched.len())
+				)),
+				", "
+			),
+			collect_join(
+				matched
+					.iter()
+					.enumerate()
+					.map(|(i, f)| format!("{} {} {}", f.column, equal, dialect.placeholder(i + 1))),
+				" AND "
+			)
 		)
-	);
+	};
 	
-	let primary_keys = fields_named.iter().filter(|f| f.primary_key).count();
-	let statement_by_id = format!(
-		"UPDATE {} SET {} WHERE {}",
-		input.get_table_name(),
-		collect_join(
-			fields_named.iter().enumerate().map(|(i, f)| format!(
-				"{} = ?{}",
-				f.ident,
-				i + 1 + primary_keys
-			)),
-			", "
-		),
-		collect_join(
-			fields_named
-				.iter()
-				.enumerate()
-				.filter(|(_, f)| f.primary_key)
-				.map(|(i, f)| format!("{} = ?{}", f.ident, i + 1)),
-			" AND "
+	let primary_keys = input.primary_keys();
+	let statement_by_id = match_dialect(|dialect| update(dialect, &primary_keys, "="));
+	// update_to matches the primary key, or every column of a table without primary key compared
+	// with an equality holding for NULL
+	let (statement_to, from_parameters) = if primary_keys.is_empty() {
+		let fields: Vec<&Field> = input.fields_iter().collect();
+		(
+			match_dialect(|dialect| update(dialect, &fields, dialect.null_safe_equal())),
+			input.to_sql_values(quote::quote! { from }),
 		)
-	);
+	} else {
+		(
+			match_dialect(|dialect| update(dialect, &primary_keys, "=")),
+			input.to_key_values(quote::quote! { from }),
+		)
+	};
 	
-	let primary_key_parameters: Vec<&syn::Ident> = fields_named.iter().map(|f| f.ident).collect();
-	let parameters: Vec<&syn::Ident> = fields_named.iter().map(|f| f.ident).collect();
+	let primary_key_parameters = input.to_key_values(quote::quote! { obj });
+	let obj_parameters = input.to_written_values(quote::quote! { obj });
+	let to_parameters = input.to_written_values(quote::quote! { to });
+	let impl_update_to_by = impl_update_to_by(input);
 	
 	quote::quote! {
+		#impl_update_to_by
+		
 		impl #name {
 			pub fn update_by_id() -> String {
+				#name::update_by_id_for(::worm::Dialect::Sqlite)
+			}
+			
+			pub fn update_by_id_for(dialect: ::worm::Dialect) -> String {
 				String::from(#statement_by_id)
 			}
 			
 			pub fn update_to() -> String {
+				#name::update_to_for(::worm::Dialect::Sqlite)
+			}
+			
+			pub fn update_to_for(dialect: ::worm::Dialect) -> String {
 				String::from(#statement_to)
 			}
+			
+			/// Statement updating the row sharing the primary key of `obj`, and its values
+			fn bind_update_by_id(dialect: ::worm::Dialect, obj: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				let params = vec![
+					#( #primary_key_parameters, )*
+					#( #obj_parameters, )*
+				];
+				Ok((#name::update_by_id_for(dialect), params))
+			}
+			
+			/// Statement updating the row of `from` with the values of `to`, and its values
+			fn bind_update_to(dialect: ::worm::Dialect, from: &#name, to: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				let params = vec![
+					#( #from_parameters, )*
+					#( #to_parameters, )*
+				];
+				Ok((#name::update_to_for(dialect), params))
+			}
+		}
+	}
+}
+
+fn table_update(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn update_by_id(&self, obj: &#name) -> ::worm::Result<usize> {
+			let (statement, params) = #name::bind_update_by_id(self.connection.dialect(), obj)?;
+			let count = self.connection.execute(statement.as_str(), &params)#wait?;
+			self.updated_row_count(count)
 		}
 		
-		impl<'a> #name_table<'a> {
-			pub fn update_by_id(&self, obj: &#name) -> Result<(), Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						conn.execute(#statement_by_id, ( #( &obj.#primary_key_parameters ),* , #( &obj.#parameters ),* ))?;
-						Ok(())
-					}
+		#table_fn update_to(&self, from: &#name, to: &#name) -> ::worm::Result<usize> {
+			let (statement, params) = #name::bind_update_to(self.connection.dialect(), from, to)?;
+			let count = self.connection.execute(statement.as_str(), &params)#wait?;
+			self.updated_row_count(count)
+		}
+	}
+}
+
+/// Generate the update of the row matching `from` on the columns nominated by the caller, for
+/// the columns update_to can't match reliably, like a float column of a table without primary key
+fn impl_update_to_by(input: &Input) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let column = input.ident("Column");
+	let statement = format!("UPDATE {} SET ", input.get_table_name());
+	let written: Vec<&String> = input.written_fields().map(|f| &f.column).collect();
+	let variants: Vec<syn::Ident> = input.fields_iter().map(Field::variant).collect();
+	let from_values: Vec<proc_macro2::TokenStream> = input
+		.fields_iter()
+		.map(|f| {
+			let ident = f.ident;
+			f.bind_value(quote::quote! { &from.#ident })
+		})
+		.collect();
+	let to_parameters = input.to_written_values(quote::quote! { to });
+	let methods = Execution::enabled().into_iter().map(|exec| table_update_to_by(input, exec));
+	
+	quote::quote! {
+		impl #name {
+			pub fn update_to_by(columns: &[#column]) -> Option<String> {
+				#name::update_to_by_for(::worm::Dialect::Sqlite, columns)
+			}
+			
+			/// Statement updating the rows matching the values of `columns`, compared with an
+			/// equality holding for NULL, or `None` when no column is nominated
+			pub fn update_to_by_for(dialect: ::worm::Dialect, columns: &[#column]) -> Option<String> {
+				if columns.is_empty() {
+					return None;
 				}
+				let set: Vec<String> = [ #( #written ),* ]
+					.iter()
+					.enumerate()
+					.map(|(i, c)| format!("{} = {}", c, dialect.placeholder(i + 1 + columns.len())))
+					.collect();
+				let condition: Vec<String> = columns
+					.iter()
+					.enumerate()
+					.map(|(i, c)| format!("{} {} {}", c.name(), dialect.null_safe_equal(), dialect.placeholder(i + 1)))
+					.collect();
+				Some(format!("{}{} WHERE {}", #statement, set.join(", "), condition.join(" AND ")))
 			}
 			
-			pub fn update_to(&self, from: &#name, to: &#name) -> Result<(), Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						conn.execute(#statement_to, ( #( &from.#parameters ),* , #( &to.#parameters ),* ))?;
-						Ok(())
-					}
+			/// Statement of `update_to_by`, and the values of `from` it matches followed by the values
+			/// of `to`
+			fn bind_update_to_by(
+				dialect: ::worm::Dialect,
+				columns: &[#column],
+				from: &#name,
+				to: &#name,
+			) -> ::worm::Result<Option<(String, Vec<::worm::Value>)>> {
+				let statement = match #name::update_to_by_for(dialect, columns) {
+					Some(statement) => statement,
+					None => return Ok(None),
+				};
+				let mut params = vec![];
+				for column in columns {
+					params.push(match column {
+						#( #column::#variants => #from_values, )*
+					});
 				}
+				params.extend([ #( #to_parameters ),* ]);
+				Ok(Some((statement, params)))
+			}
+		}
+		
+		#( #methods )*
+	}
+}
+
+fn table_update_to_by(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let table = exec.table(input);
+	let column = input.ident("Column");
+	let (inherent_fn, wait) = (exec.inherent_fn(), exec.wait());
+	
+	quote::quote! {
+		impl<'a> #table<'a> {
+			/// Update the row matching `from` on `columns` with the values of `to`, returning the
+			/// number of rows updated
+			#inherent_fn update_to_by(&self, columns: &[#column], from: &#name, to: &#name) -> ::worm::Result<usize> {
+				let count = match #name::bind_update_to_by(self.connection.dialect(), columns, from, to)? {
+					Some((statement, params)) => self.connection.execute(statement.as_str(), &params)#wait?,
+					None => 0,
+				};
+				self.updated_row_count(count)
 			}
 		}
 	}
@@ -233,180 +532,400 @@ fn impl_update(input: &Input) -> proc_macro2::TokenStream {
 
 fn impl_select_all(input: &Input) -> proc_macro2::TokenStream {
 	let name = input.name;
-	let name_table = &input.name_table;
+	let statement = format!(
+		"SELECT {} FROM {}",
+		collect_join(input.fields_iter().map(|f| f.column.clone()), ", "),
+		input.get_table_name()
+	);
 	
 	quote::quote! {
 		impl #name {
-			pub fn select_all() -> String {
-				#name::select(SelectBuilder::default().build())
+			pub fn select_all() -> (String, Vec<::worm::Value>) {
+				(String::from(#statement), vec![])
 			}
 		}
+	}
+}
+
+fn table_select_all(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let select = input.ident("Select");
+	let select_builder = input.ident("SelectBuilder");
+	let count = input.ident("Count");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn select_all(&self) -> ::worm::Result<Vec<#name>> {
+			self.select(#select_builder::default().build())#wait
+		}
 		
-		impl<'a> #name_table<'a> {
-			pub fn select_all(&self) -> Result<Vec<#name>, Box<dyn std::error::Error>> {
-				self.select(SelectBuilder::default().build())
-			}
+		#table_fn select_page(&self, mut select: #select, page: usize, per_page: usize) -> ::worm::Result<::worm::Page<#name>> {
+			let offset = page.checked_mul(per_page).ok_or_else(|| {
+				::worm::Error::Conversion(format!("The offset of page {} of {} rows overflows", page, per_page))
+			})?;
+			let total = self.count(#count { filter: select.filter.clone() })#wait?;
+			select.limit = Some(per_page);
+			select.offset = Some(offset);
+			let rows = self.select(select)#wait?;
+			Ok(::worm::Page { rows, page, per_page, total })
 		}
 	}
 }
 
-fn impl_select_one(input: &Input) -> proc_macro2::TokenStream {
+fn table_select_one(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
 	let name = input.name;
-	let name_table = &input.name_table;
+	let select_one = input.ident("SelectOne");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
 	
 	quote::quote! {
-		impl<'a> #name_table<'a> {
-			pub fn select_one(&self, select_one: SelectOne) -> Result<Option<#name>, Box<dyn std::error::Error>> {
-				let r = self.select(select_one.into())?;
-				Ok(r.into_iter().nth(0))
-			}
+		#table_fn select_one(&self, select_one: #select_one) -> ::worm::Result<Option<#name>> {
+			let r = self.select(select_one.into())#wait?;
+			Ok(r.into_iter().next())
 		}
 	}
 }
 
 fn impl_select(input: &Input) -> proc_macro2::TokenStream {
 	let name = input.name;
-	let name_table = &input.name_table;
+	let filter = input.ident("Filter");
+	let select = input.ident("Select");
 	let fields_named = &input.fields();
 	
 	let statement = format!(
 		"SELECT {} FROM {}",
-		collect_join(fields_named.iter().map(|f| format!("{}", f.ident)), ", "),
+		collect_join(fields_named.iter().map(|f| f.column.clone()), ", "),
 		input.get_table_name()
 	);
 	let fields: Vec<&syn::Ident> = fields_named.iter().map(|f| f.ident).collect();
-	let fields_assignment: Vec<proc_macro2::TokenStream> = fields_named
+	let values: Vec<proc_macro2::TokenStream> = fields_named
 		.iter()
-		.enumerate()
-		.map(|(i, _)| quote::quote! { r.get(#i)? })
+		.map(|f| f.read_value(quote::quote! { values.next().unwrap_or(::worm::Value::Null) }))
 		.collect();
+	let skipped = &input.skipped;
 	
 	quote::quote! {
 		impl #name {
-			pub fn select(select: Select) -> String {
+			pub fn select(select: #select) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				#name::select_for(::worm::Dialect::Sqlite, select)
+			}
+			
+			pub fn select_for(dialect: ::worm::Dialect, select: #select) -> ::worm::Result<(String, Vec<::worm::Value>)> {
 				let mut statement = #statement.to_string();
+				let mut params = vec![];
 				if let Some(filter) = select.filter {
-					statement += format!(" WHERE {}", Filter::to_condition(&filter)).as_str();
+					statement += format!(" WHERE {}", #filter::to_condition(&filter, dialect, &mut params)?).as_str();
 				}
-				if let Some(limit) = select.limit {
-					statement += format!(" LIMIT {}", limit).as_str();
+				if !select.order_by.is_empty() {
+					let terms: Vec<&str> = select.order_by.iter().map(|o| o.to_term()).collect();
+					statement += format!(" ORDER BY {}", terms.join(", ")).as_str();
 				}
-				if let Some(offset) = select.offset {
-					statement += format!(" ORDER BY ( SELECT NULL ) OFFSET {}", offset).as_str();
-				}
-				statement
+				statement += dialect.limit_offset(select.limit, select.offset).as_str();
+				Ok((statement, params))
 			}
 		}
 		
-		impl<'a> #name_table<'a> {
-			pub fn select(&self, select: Select) -> Result<Vec<#name>, Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						let statement = #name::select(select);
-						let mut s = conn.prepare(statement.as_str())?;
-						let r = s.query_map([], |r| Ok( #name { #( #fields : #fields_assignment ),* } ) )?
-							.collect::<Result<Vec<#name>, rusqlite::Error>>()?;
-						Ok(r)
-					}
-				}
+		impl ::worm::FromRow for #name {
+			fn from_row(row: Vec<::worm::Value>) -> ::worm::Result<Self> {
+				let mut values = row.into_iter();
+				Ok(#name {
+					#( #fields: #values?, )*
+					#( #skipped: Default::default(), )*
+				})
 			}
 		}
 	}
 }
 
+fn table_select(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let select = input.ident("Select");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn select(&self, select: #select) -> ::worm::Result<Vec<#name>> {
+			let (statement, params) = #name::select_for(self.connection.dialect(), select)?;
+			self.connection
+				.query(statement.as_str(), &params)#wait?
+				.into_iter()
+				.map(::worm::FromRow::from_row)
+				.collect()
+		}
+	}
+}
+
 fn impl_count_all(input: &Input) -> proc_macro2::TokenStream {
 	let name = &input.name;
-	let name_table = &input.name_table;
+	let statement = format!("SELECT COUNT(*) FROM {}", input.get_table_name());
+	
 	quote::quote! {
 		impl #name {
-			pub fn count_all_statement() -> String {
-				#name::count_statement(CountBuilder::default().build())
+			pub fn count_all_statement() -> (String, Vec<::worm::Value>) {
+				(String::from(#statement), vec![])
 			}
 		}
-		
-		impl<'a> #name_table<'a> {
-			pub fn count_all(&self) -> Result<usize, Box<dyn std::error::Error>> {
-			  self.count(CountBuilder::default().build())
-			}
+	}
+}
+
+fn table_count_all(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let count_builder = input.ident("CountBuilder");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn count_all(&self) -> ::worm::Result<usize> {
+			self.count(#count_builder::default().build())#wait
 		}
 	}
 }
 
 fn impl_count(input: &Input) -> proc_macro2::TokenStream {
 	let name = &input.name;
-	let name_table = &input.name_table;
-	let fields_named = &input.fields();
+	let filter = input.ident("Filter");
+	let count = input.ident("Count");
 	
-	let statement = format!(
-		"SELECT COUNT( {} ) FROM {}",
-		fields_named.iter().next().unwrap().ident,
-		input.get_table_name()
-	);
+	let statement = format!("SELECT COUNT(*) FROM {}", input.get_table_name());
 	
 	quote::quote! {
 		impl #name {
-			pub fn count_statement(count: Count) -> String {
+			pub fn count_statement(count: #count) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				#name::count_statement_for(::worm::Dialect::Sqlite, count)
+			}
+			
+			pub fn count_statement_for(dialect: ::worm::Dialect, count: #count) -> ::worm::Result<(String, Vec<::worm::Value>)> {
 				let mut statement = #statement.to_string();
+				let mut params = vec![];
 				if let Some(filter) = count.filter {
-					statement += format!(" WHERE {}", Filter::to_condition(&filter)).as_str();
+					statement += format!(" WHERE {}", #filter::to_condition(&filter, dialect, &mut params)?).as_str();
 				}
-				statement
+				Ok((statement, params))
 			}
 		}
-		
-		impl<'a> #name_table<'a> {
-			pub fn count(&self, count: Count) -> Result<usize, Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						let statement = #name::count_statement(count);
-						let mut s = conn.prepare(statement.as_str())?;
-						let r = s.query_map([], |r| r.get(0))?.collect::<Result<Vec<usize>, rusqlite::Error>>()?;
-						if r.len() == 1 {
-							Ok(r[0])
-						} else {
-							Err(format!("SELECT COUNT result is expected to have length of 1. Current result is {:?}", r).into())
-						}
-					}
-				}
+	}
+}
+
+fn table_count(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = &input.name;
+	let count = input.ident("Count");
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn count(&self, count: #count) -> ::worm::Result<usize> {
+			let (statement, params) = #name::count_statement_for(self.connection.dialect(), count)?;
+			let r = self.connection.query(statement.as_str(), &params)#wait?;
+			if r.len() == 1 {
+				let value = r.into_iter().flatten().next().unwrap_or(::worm::Value::Null);
+				::worm::FromSqlValue::from_sql_value(value)
+			} else {
+				Err(::worm::Error::UnexpectedRowCount { expected: 1, actual: r.len() })
 			}
 		}
 	}
 }
 
-fn impl_insert(input: &Input) -> proc_macro2::TokenStream {
-	let name = input.name;
-	let name_table = &input.name_table;
-	
-	let statement = format!(
+/// INSERT statement of a row, binding the written fields
+pub fn insert_into(input: &Input, dialect: Dialect) -> String {
+	if input.written_fields().next().is_none() {
+		return format!("INSERT INTO {} DEFAULT VALUES", input.get_table_name());
+	}
+	format!(
 		"INSERT INTO {} ({}) VALUES ({})",
 		input.get_table_name(),
-		collect_join(input.fields_iter().map(|f| format!("{}", f.ident)), ", "),
+		collect_join(input.written_fields().map(|f| f.column.clone()), ", "),
 		collect_join(
 			input
-				.fields_iter()
+				.written_fields()
 				.enumerate()
-				.map(|(i, _)| format!("?{}", i + 1)),
+				.map(|(i, _)| dialect.placeholder(i + 1)),
 			", "
 		),
+	)
+}
+
+fn impl_insert(input: &Input) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let id = input.id_type();
+	
+	// A generated key is read back from the inserted row
+	let returning_key = match input.auto_key() {
+		Some(field) => format!(" RETURNING {}", field.column),
+		None => String::new(),
+	};
+	let returning = format!(
+		" RETURNING {}",
+		collect_join(input.fields_iter().map(|f| f.column.clone()), ", ")
 	);
+	let statement = match_dialect(|dialect| insert_into(input, dialect) + returning_key.as_str());
+	let statement_returning = match_dialect(|dialect| insert_into(input, dialect) + returning.as_str());
+	
+	let parameters = input.to_insert_values(quote::quote! { i });
+	
+	// Rows are inserted by chunks of a multi-row VALUES, within the parameters of the dialect
+	let columns = input.written_fields().count();
+	let insert_rows = if columns == 0 {
+		let statement = format!("INSERT INTO {} DEFAULT VALUES", input.get_table_name());
+		quote::quote! {
+			let _ = (dialect, rows);
+			String::from(#statement)
+		}
+	} else {
+		let statement = format!(
+			"INSERT INTO {} ({}) VALUES ",
+			input.get_table_name(),
+			collect_join(input.written_fields().map(|f| f.column.clone()), ", ")
+		);
+		quote::quote! {
+			let values: Vec<String> = (0..rows)
+				.map(|row| {
+					let placeholders: Vec<String> = (1..=#columns)
+						.map(|column| dialect.placeholder(row * #columns + column))
+						.collect();
+					format!("({})", placeholders.join(", "))
+				})
+				.collect();
+			format!("{}{}", #statement, values.join(", "))
+		}
+	};
+	let (chunk, row) = match columns {
+		0 => (quote::quote! { 1 }, quote::quote! { _ }),
+		columns => (quote::quote! { (dialect.max_parameters() / #columns).max(1) }, quote::quote! { i }),
+	};
 	
-	let parameters: Vec<&syn::Ident> = input.fields_iter().map(|f| f.ident).collect();
+	// The key generated by the database is read back from the inserted row, the other ids are
+	// known before the insert
+	let bind_insert = match (input.auto_key(), input.generated_key()) {
+		(Some(_), _) => quote::quote! {
+			/// Statement inserting `i`, returning its key, and its values
+			fn bind_insert(dialect: ::worm::Dialect, i: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				Ok((#name::insert_for(dialect), vec![ #( #parameters ),* ]))
+			}
+		},
+		// A nil uuid key is replaced before the insert, and returned
+		(None, Some(field)) => {
+			let ident = field.ident;
+			let version = &field.generate;
+			let parameters = input.to_insert_values_with_id(quote::quote! { i });
+			quote::quote! {
+				/// Statement inserting `i`, its values and the id of the row
+				fn bind_insert(dialect: ::worm::Dialect, i: &#name) -> ::worm::Result<(String, Vec<::worm::Value>, #id)> {
+					let id = #version.or_generate(&i.#ident);
+					let params = vec![ #( #parameters ),* ];
+					Ok((#name::insert_for(dialect), params, id))
+				}
+			}
+		}
+		(None, None) => quote::quote! {
+			/// Statement inserting `i`, its values and the id of the row
+			fn bind_insert(dialect: ::worm::Dialect, i: &#name) -> ::worm::Result<(String, Vec<::worm::Value>, #id)> {
+				Ok((#name::insert_for(dialect), vec![ #( #parameters ),* ], ()))
+			}
+		},
+	};
 	
 	quote::quote! {
 		impl #name {
 			pub fn insert() -> String {
+				#name::insert_for(::worm::Dialect::Sqlite)
+			}
+			
+			pub fn insert_for(dialect: ::worm::Dialect) -> String {
 				String::from(#statement)
 			}
-		}
-		
-		impl<'a> #name_table<'a> {
-			pub fn insert(&self, i: &#name) -> Result<(), Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						conn.execute(#statement, ( #( &i.#parameters ),* ))?;
-						Ok(())
+			
+			pub fn insert_returning() -> String {
+				#name::insert_returning_for(::worm::Dialect::Sqlite)
+			}
+			
+			pub fn insert_returning_for(dialect: ::worm::Dialect) -> String {
+				String::from(#statement_returning)
+			}
+			
+			/// Statement inserting `rows` rows at once
+			pub fn insert_rows_for(dialect: ::worm::Dialect, rows: usize) -> String {
+				#insert_rows
+			}
+			
+			pub fn insert_many<'r, I: IntoIterator<Item = &'r #name>>(rows: I) -> ::worm::Result<Vec<(String, Vec<::worm::Value>)>> {
+				#name::insert_many_for(::worm::Dialect::Sqlite, rows)
+			}
+			
+			/// Statements inserting `rows`, by chunks of rows within the parameters of the dialect
+			pub fn insert_many_for<'r, I: IntoIterator<Item = &'r #name>>(
+				dialect: ::worm::Dialect,
+				rows: I,
+			) -> ::worm::Result<Vec<(String, Vec<::worm::Value>)>> {
+				let chunk = #chunk;
+				let statement = #name::insert_rows_for(dialect, chunk);
+				let mut statements = vec![];
+				let mut params: Vec<::worm::Value> = vec![];
+				let mut count = 0;
+				for #row in rows {
+					params.extend([ #( #parameters ),* ]);
+					count += 1;
+					if count == chunk {
+						statements.push((statement.clone(), std::mem::take(&mut params)));
+						count = 0;
 					}
 				}
+				if count > 0 {
+					statements.push((#name::insert_rows_for(dialect, count), params));
+				}
+				Ok(statements)
+			}
+			
+			#bind_insert
+			
+			/// Statement inserting `i`, returning the row as stored, and its values
+			fn bind_insert_returning(dialect: ::worm::Dialect, i: &#name) -> ::worm::Result<(String, Vec<::worm::Value>)> {
+				Ok((#name::insert_returning_for(dialect), vec![ #( #parameters ),* ]))
+			}
+		}
+	}
+}
+
+fn table_insert(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let id = input.id_type();
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	let insert = match input.auto_key() {
+		Some(field) => {
+			let value = field.read_value(quote::quote! { value });
+			quote::quote! {
+				let (statement, params) = #name::bind_insert(self.connection.dialect(), i)?;
+				let r = self.connection.query(statement.as_str(), &params)#wait?;
+				let value = r.into_iter().flatten().next().unwrap_or(::worm::Value::Null);
+				#value
+			}
+		}
+		None => quote::quote! {
+			let (statement, params, id) = #name::bind_insert(self.connection.dialect(), i)?;
+			self.connection.execute(statement.as_str(), &params)#wait?;
+			Ok(id)
+		},
+	};
+	
+	quote::quote! {
+		#table_fn insert(&self, i: &#name) -> ::worm::Result<#id> {
+			#insert
+		}
+		
+		#table_fn insert_many(&self, rows: &[#name]) -> ::worm::Result<usize> {
+			self.insert_iter(rows)#wait
+		}
+		
+		#table_fn insert_iter<'r, I: IntoIterator<Item = &'r #name>>(&self, rows: I) -> ::worm::Result<usize>
+		where
+			#name: 'r,
+		{
+			let statements = #name::insert_many_for(self.connection.dialect(), rows)?;
+			self.connection.execute_many(&statements)#wait
+		}
+		
+		#table_fn insert_returning(&self, i: &#name) -> ::worm::Result<#name> {
+			let (statement, params) = #name::bind_insert_returning(self.connection.dialect(), i)?;
+			let r = self.connection.query(statement.as_str(), &params)#wait?;
+			match r.into_iter().next() {
+				Some(row) => ::worm::FromRow::from_row(row),
+				None => Err(::worm::Error::UnexpectedRowCount { expected: 1, actual: 0 }),
 			}
 		}
 	}
@@ -414,7 +933,6 @@ fn impl_insert(input: &Input) -> proc_macro2::TokenStream {
 
 fn impl_delete_table(input: &Input) -> proc_macro2::TokenStream {
 	let name = &input.name;
-	let name_table = &input.name_table;
 	let statement = format!("DROP TABLE {}", input.get_table_name());
 	
 	quote::quote! {
@@ -423,75 +941,117 @@ fn impl_delete_table(input: &Input) -> proc_macro2::TokenStream {
 				String::from(#statement)
 			}
 		}
-		
-		impl<'a> #name_table<'a> {
-			pub fn delete_table(&self) -> Result<(), Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						conn.execute(#statement, ())?;
-						Ok(())
-					},
-				}
-			}
+	}
+}
+
+fn table_delete_table(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = &input.name;
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn delete_table(&self) -> ::worm::Result<()> {
+			self.connection.execute(#name::delete_table().as_str(), &[])#wait?;
+			Ok(())
 		}
 	}
 }
 
 fn impl_create_table(input: &Input) -> proc_macro2::TokenStream {
 	let name = input.name;
-	let name_table = &input.name_table;
 	
-	let fields_statement = collect_join(
-		input.fields_iter().map(|field| {
-			let sql_type = SqlType::to_string(&field.sql_type);
-			
-			let ident = field.ident.to_string();
-			let mut attributes = vec![ident.as_str(), sql_type.as_ref()];
-			
-			if field.primary_key {
-				attributes.push("NOT NULL PRIMARY KEY");
-			} else if let Some(nullable) = field.nullable {
-				if nullable {
-					attributes.push("NULL");
+	// A key of several columns is declared after the columns, SQLite rejects more than one
+	// PRIMARY KEY column constraint
+	let primary_keys = input.primary_keys();
+	let composite = primary_keys.len() > 1;
+	let table_constraint = if composite {
+		format!(
+			", PRIMARY KEY ({})",
+			escape_format(&collect_join(primary_keys.iter().map(|f| f.column.clone()), ", "))
+		)
+	} else {
+		String::new()
+	};
+	
+	// The type and the values of an enumeration are only known at run time, through worm::Column
+	let statement = match_dialect_format(|dialect| {
+		let mut args = vec![];
+		let fields_statement = collect_join(
+			input.fields_iter().map(|field| {
+				let ty = field.value_ty;
+				let column = escape_format(&field.column);
+				let sql_type = if field.sql_type.is_enumeration() {
+					args.push(quote::quote! { <#ty as ::worm::Column>::sql_type(dialect) });
+					String::from("{}")
 				} else {
+					escape_format(&SqlType::to_string(&field.sql_type, dialect))
+				};
+				
+				let mut attributes = vec![column.as_str(), sql_type.as_str()];
+				
+				if field.auto {
+					attributes.push(dialect.auto_key());
+				} else if field.primary_key && composite {
 					attributes.push("NOT NULL");
+				} else if field.primary_key {
+					attributes.push("NOT NULL PRIMARY KEY");
+				} else if let Some(nullable) = field.nullable {
+					if nullable {
+						attributes.push("NULL");
+					} else {
+						attributes.push("NOT NULL");
+					}
 				}
-			}
-			if field.unique {
-				attributes.push("UNIQUE");
-			}
-			
-			collect_join_str(attributes.into_iter().filter(|str| !str.is_empty()), " ")
-		}),
-		", ",
-	);
-	
-	let statement = format!(
-		"CREATE TABLE IF NOT EXISTS {} ( {} )",
-		input.get_table_name(),
-		fields_statement
-	);
+				if field.unique {
+					attributes.push("UNIQUE");
+				}
+				let check = format!("CHECK ({} IN ({{}}))", column);
+				if field.check {
+					args.push(quote::quote! { <#ty as ::worm::Column>::sql_literals().join(", ") });
+					attributes.push(check.as_str());
+				}
+				
+				collect_join_str(attributes.into_iter().filter(|str| !str.is_empty()), " ")
+			}),
+			", ",
+		);
+		
+		(
+			format!(
+				"CREATE TABLE IF NOT EXISTS {} ( {}{} )",
+				escape_format(&input.get_table_name()),
+				fields_statement,
+				table_constraint
+			),
+			args,
+		)
+	});
 	
 	quote::quote! {
 		impl #name {
 			pub fn create_table() -> String {
-				String::from(#statement)
+				#name::create_table_for(::worm::Dialect::Sqlite)
 			}
-		}
-		
-		impl<'a> #name_table <'a> {
-			pub fn create_table(&self) -> Result<(), Box<dyn std::error::Error>> {
-				match self.connection {
-					SqlConnection::Rusqlite(conn) => {
-						conn.execute(#statement, ())?;
-						Ok(())
-					}
-				}
+			
+			pub fn create_table_for(dialect: ::worm::Dialect) -> String {
+				#statement
 			}
 		}
 	}
 }
 
+fn table_create_table(input: &Input, exec: Execution) -> proc_macro2::TokenStream {
+	let name = input.name;
+	let (table_fn, wait) = (exec.table_fn(), exec.wait());
+	
+	quote::quote! {
+		#table_fn create_table(&self) -> ::worm::Result<()> {
+			let statement = #name::create_table_for(self.connection.dialect());
+			self.connection.execute(statement.as_str(), &[])#wait?;
+			Ok(())
+		}
+	}
+}
+
 fn collect_join_str<'a, T: Iterator<Item = &'a str>>(iter: T, separator: &str) -> String {
 	iter.collect::<Vec<&str>>().join(separator)
 }
@@ -0,0 +1,13 @@
+use crate::{Dialect, FromSqlValue, ToSqlValue};
+
+/// A fieldless enum stored in an `#[enumeration]` column, implemented by `#[derive(Column)]`
+///
+/// The variants are stored either as their names in a TEXT column or as their discriminants in an
+/// INTEGER column.
+pub trait Column: ToSqlValue + FromSqlValue {
+	/// SQL type of the column
+	fn sql_type(dialect: Dialect) -> &'static str;
+	
+	/// The value of every variant as an SQL literal, listed by the CHECK constraint of the column
+	fn sql_literals() -> Vec<String>;
+}
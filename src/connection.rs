@@ -0,0 +1,113 @@
+use crate::{Dialect, Result, Value};
+
+/// Connection to the SQL database, specified as part of the constructor of a generated table
+pub enum Connection<'a> {
+	#[cfg(feature = "rusqlite")]
+	Rusqlite(&'a rusqlite::Connection),
+	#[cfg(feature = "postgres")]
+	Postgres(std::cell::RefCell<&'a mut postgres::Client>),
+	/// Stand-in of a build without synchronous driver, which cannot be constructed
+	#[cfg(not(any(feature = "rusqlite", feature = "postgres")))]
+	#[doc(hidden)]
+	Unavailable(std::marker::PhantomData<&'a ()>, std::convert::Infallible),
+}
+
+// The statements of a build without synchronous driver go nowhere
+#[cfg_attr(not(any(feature = "rusqlite", feature = "postgres")), allow(unused_variables))]
+impl<'a> Connection<'a> {
+	/// Dialect of the statements sent through the connection
+	pub fn dialect(&self) -> Dialect {
+		match self {
+			#[cfg(feature = "rusqlite")]
+			Connection::Rusqlite(_) => Dialect::Sqlite,
+			#[cfg(feature = "postgres")]
+			Connection::Postgres(_) => Dialect::Postgres,
+			#[cfg(not(any(feature = "rusqlite", feature = "postgres")))]
+			Connection::Unavailable(_, never) => match *never {},
+		}
+	}
+	
+	/// Execute a statement, returning the number of rows affected
+	pub fn execute(&self, statement: &str, params: &[Value]) -> Result<usize> {
+		match self {
+			#[cfg(feature = "rusqlite")]
+			Connection::Rusqlite(conn) => crate::rusqlite::execute(conn, statement, params),
+			#[cfg(feature = "postgres")]
+			Connection::Postgres(client) => {
+				crate::postgres::execute(&mut client.borrow_mut(), statement, params)
+			}
+			#[cfg(not(any(feature = "rusqlite", feature = "postgres")))]
+			Connection::Unavailable(_, never) => match *never {},
+		}
+	}
+	
+	/// Execute the statements in a single transaction, preparing a statement once for consecutive
+	/// executions, and return the number of rows affected
+	pub fn execute_many(&self, statements: &[(String, Vec<Value>)]) -> Result<usize> {
+		match self {
+			#[cfg(feature = "rusqlite")]
+			Connection::Rusqlite(conn) => crate::rusqlite::execute_many(conn, statements),
+			#[cfg(feature = "postgres")]
+			Connection::Postgres(client) => crate::postgres::execute_many(&mut client.borrow_mut(), statements),
+			#[cfg(not(any(feature = "rusqlite", feature = "postgres")))]
+			Connection::Unavailable(_, never) => match *never {},
+		}
+	}
+	
+	/// Execute a query, returning the values of every row in the order of the selected columns
+	pub fn query(&self, statement: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+		match self {
+			#[cfg(feature = "rusqlite")]
+			Connection::Rusqlite(conn) => crate::rusqlite::query(conn, statement, params),
+			#[cfg(feature = "postgres")]
+			Connection::Postgres(client) => {
+				crate::postgres::query(&mut client.borrow_mut(), statement, params)
+			}
+			#[cfg(not(any(feature = "rusqlite", feature = "postgres")))]
+			Connection::Unavailable(_, never) => match *never {},
+		}
+	}
+}
+
+/// Connection pool of an asynchronous generated table, specified as part of the constructor
+#[cfg(feature = "sqlx")]
+pub enum AsyncConnection<'a> {
+	SqlxSqlite(&'a sqlx::SqlitePool),
+	SqlxPostgres(&'a sqlx::PgPool),
+}
+
+#[cfg(feature = "sqlx")]
+impl<'a> AsyncConnection<'a> {
+	/// Dialect of the statements sent through the connection
+	pub fn dialect(&self) -> Dialect {
+		match self {
+			AsyncConnection::SqlxSqlite(_) => Dialect::Sqlite,
+			AsyncConnection::SqlxPostgres(_) => Dialect::Postgres,
+		}
+	}
+	
+	/// Execute a statement, returning the number of rows affected
+	pub async fn execute(&self, statement: &str, params: &[Value]) -> Result<usize> {
+		match self {
+			AsyncConnection::SqlxSqlite(pool) => crate::sqlx::execute_sqlite(pool, statement, params).await,
+			AsyncConnection::SqlxPostgres(pool) => crate::sqlx::execute_postgres(pool, statement, params).await,
+		}
+	}
+	
+	/// Execute the statements in a single transaction, preparing a statement once for consecutive
+	/// executions, and return the number of rows affected
+	pub async fn execute_many(&self, statements: &[(String, Vec<Value>)]) -> Result<usize> {
+		match self {
+			AsyncConnection::SqlxSqlite(pool) => crate::sqlx::execute_many_sqlite(pool, statements).await,
+			AsyncConnection::SqlxPostgres(pool) => crate::sqlx::execute_many_postgres(pool, statements).await,
+		}
+	}
+	
+	/// Execute a query, returning the values of every row in the order of the selected columns
+	pub async fn query(&self, statement: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+		match self {
+			AsyncConnection::SqlxSqlite(pool) => crate::sqlx::query_sqlite(pool, statement, params).await,
+			AsyncConnection::SqlxPostgres(pool) => crate::sqlx::query_postgres(pool, statement, params).await,
+		}
+	}
+}
@@ -0,0 +1,49 @@
+/// SQL dialect in which the statements are rendered
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+	Sqlite,
+	Postgres,
+}
+
+impl Dialect {
+	/// Placeholder of the parameter at `index`, starting from 1
+	pub fn placeholder(&self, index: usize) -> String {
+		match self {
+			Dialect::Sqlite => format!("?{}", index),
+			Dialect::Postgres => format!("${}", index),
+		}
+	}
+	
+	/// Equality operator holding for two NULL values
+	pub fn null_safe_equal(&self) -> &'static str {
+		match self {
+			Dialect::Sqlite => "IS",
+			Dialect::Postgres => "IS NOT DISTINCT FROM",
+		}
+	}
+	
+	/// Maximum number of parameters bound to a statement
+	///
+	/// SQLite accepts 32766 parameters since 3.32, the lower limit of older versions is kept for a
+	/// database opened by a system library.
+	pub fn max_parameters(&self) -> usize {
+		match self {
+			Dialect::Sqlite => 999,
+			Dialect::Postgres => 65535,
+		}
+	}
+	
+	/// LIMIT and OFFSET clauses of a SELECT statement, with a leading space when not empty
+	///
+	/// SQLite only accepts OFFSET after a LIMIT, so a negative limit - no limit - is rendered when
+	/// only an offset is given.
+	pub fn limit_offset(&self, limit: Option<usize>, offset: Option<usize>) -> String {
+		match (self, limit, offset) {
+			(_, None, None) => String::new(),
+			(_, Some(limit), None) => format!(" LIMIT {}", limit),
+			(_, Some(limit), Some(offset)) => format!(" LIMIT {} OFFSET {}", limit, offset),
+			(Dialect::Sqlite, None, Some(offset)) => format!(" LIMIT -1 OFFSET {}", offset),
+			(Dialect::Postgres, None, Some(offset)) => format!(" OFFSET {}", offset),
+		}
+	}
+}
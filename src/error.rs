@@ -0,0 +1,53 @@
+use std::fmt;
+
+/// Result of the operations on a worm table
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error returned by the operations on a worm table
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+	/// A UNIQUE constraint failed
+	UniqueViolation(String),
+	/// A PRIMARY KEY constraint failed
+	PrimaryKeyViolation(String),
+	/// A NOT NULL constraint failed
+	NotNullViolation(String),
+	/// No row matched the query
+	NotFound,
+	/// The query returned or affected a different number of rows than expected
+	UnexpectedRowCount { expected: usize, actual: usize },
+	/// A value could not be converted between its Rust and SQL representations
+	Conversion(String),
+	/// Any other error raised by the underlying database wrapper
+	Driver(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::UniqueViolation(message) => write!(f, "UNIQUE constraint failed: {}", message),
+			Error::PrimaryKeyViolation(message) => {
+				write!(f, "PRIMARY KEY constraint failed: {}", message)
+			}
+			Error::NotNullViolation(message) => write!(f, "NOT NULL constraint failed: {}", message),
+			Error::NotFound => write!(f, "No row found"),
+			Error::UnexpectedRowCount { expected, actual } => write!(
+				f,
+				"Expected {} row(s), the statement resulted in {}",
+				expected, actual
+			),
+			Error::Conversion(message) => write!(f, "Conversion failed: {}", message),
+			Error::Driver(e) => e.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Driver(e) => Some(e.as_ref()),
+			_ => None,
+		}
+	}
+}
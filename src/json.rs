@@ -0,0 +1,70 @@
+//! JSON columns, serialized with [serde_json](https://docs.rs/serde_json)
+
+use crate::{Dialect, Error, Result, Value};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub use serde_json::Value as JsonValue;
+
+/// Conversion of the fields of the `#[json]` columns
+///
+/// A field serializing to `null`, like `None`, is stored as an SQL NULL. Binding a value that
+/// serde_json can't serialize, like a map with non-string keys, fails with `Error::Conversion`.
+pub trait JsonStorage: Sized {
+	fn to_json_value(&self) -> Result<Value>;
+	fn from_json_value(value: Value) -> Result<Self>;
+}
+
+impl<T: Serialize + DeserializeOwned> JsonStorage for T {
+	fn to_json_value(&self) -> Result<Value> {
+		match serde_json::to_value(self) {
+			Ok(JsonValue::Null) => Ok(Value::Null),
+			Ok(json) => Ok(Value::Json(json.to_string())),
+			Err(e) => Err(Error::Conversion(format!("Unable to serialize the value of a JSON column: {}", e))),
+		}
+	}
+	
+	fn from_json_value(value: Value) -> Result<Self> {
+		let json = match value {
+			Value::Null => Ok(T::deserialize(JsonValue::Null)),
+			Value::Json(v) | Value::Text(v) => Ok(serde_json::from_str(&v)),
+			value => Err(Error::Conversion(format!("Unable to convert {:?} to JSON", value))),
+		}?;
+		json.map_err(|e| Error::Conversion(e.to_string()))
+	}
+}
+
+/// Render the condition of a `PathEqual` filter, comparing the value at `path` in the document of
+/// `column` with `value`
+///
+/// SQLite extracts the value with `json_extract` and Postgres with `jsonb_path_query_first`, both
+/// taking a path such as `$.a.b`.
+pub fn path_equal(dialect: Dialect, column: &str, path: &str, value: &JsonValue, params: &mut Vec<Value>) -> String {
+	params.push(Value::Text(path.to_string()));
+	let path = dialect.placeholder(params.len());
+	match dialect {
+		Dialect::Sqlite => {
+			// json_extract returns SQL values, with 1 and 0 for the booleans
+			params.push(match value {
+				JsonValue::Null => Value::Null,
+				JsonValue::Bool(v) => Value::Integer((*v).into()),
+				JsonValue::Number(v) => match v.as_i64() {
+					Some(v) => Value::Integer(v),
+					None => Value::Real(v.as_f64().unwrap_or(f64::NAN)),
+				},
+				JsonValue::String(v) => Value::Text(v.clone()),
+				value => Value::Text(value.to_string()),
+			});
+			format!("json_extract({}, {}) = {}", column, path, dialect.placeholder(params.len()))
+		}
+		Dialect::Postgres => {
+			params.push(Value::Json(value.to_string()));
+			format!(
+				"jsonb_path_query_first({}, CAST({} AS TEXT)::jsonpath) = {}",
+				column,
+				path,
+				dialect.placeholder(params.len())
+			)
+		}
+	}
+}
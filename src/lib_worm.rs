@@ -62,34 +62,34 @@
 //! person_table.insert(&jim).unwrap();
 //!
 //! // Check Jim's age
-//! let select = SelectBuilder::default().set_filter(Filter::NameEqual("Jim".to_string())).build();
+//! let select = PersonSelectBuilder::default().set_filter(PersonFilter::NameEqual("Jim".to_string())).build();
 //! let p: Person = person_table.select(select).unwrap().into_iter().nth(0).unwrap();
 //! assert!(p.age == 27);
 //! // or
-//! let filter = Filter::And(Box::new(Filter::NameEqual("Jim".to_string())), Box::new(Filter::AgeEqual(27)));
+//! let filter = PersonFilter::And(Box::new(PersonFilter::NameEqual("Jim".to_string())), Box::new(PersonFilter::AgeEqual(27)));
 //! assert!(person_table.count(filter.into()).unwrap() == 1);
 //!
 //! // Update Jim
-//! let filter = Filter::NameEqual("Jim".to_string());
+//! let filter = PersonFilter::NameEqual("Jim".to_string());
 //! let jim: Person = person_table.select_one(filter.into()).unwrap().unwrap();
 //! let update_to = Person { id: 2, name: jim.name.clone(), age: jim.age+1 };
 //! person_table.update_to(&jim, &update_to).unwrap();
 //!
-//! let filter = Filter::NameEqual("Jim".to_string());
+//! let filter = PersonFilter::NameEqual("Jim".to_string());
 //! let updated_jane: Person = person_table.select(filter.into()).unwrap().into_iter().nth(0).unwrap();
 //! assert!(updated_jane.age == 28);
 //!
 //! // Check Jim's age
-//! let p: Person = person_table.select(Filter::AgeGreaterThan(27).into()).unwrap().into_iter().nth(0).unwrap();
+//! let p: Person = person_table.select(PersonFilter::AgeGreaterThan(27).into()).unwrap().into_iter().nth(0).unwrap();
 //! assert!(p.age == 28);
 //!
 //! // Delete John
-//! let filter = Filter::And(Box::new(Filter::NameEqual("John".to_string())), Box::new(Filter::AgeEqual(24)));
+//! let filter = PersonFilter::And(Box::new(PersonFilter::NameEqual("John".to_string())), Box::new(PersonFilter::AgeEqual(24)));
 //! let john: Person = person_table.select(filter.into()).unwrap()
 //!             .into_iter().nth(0).unwrap();
 //! person_table.delete(john.into()).unwrap();
 //!
-//! let filter = Filter::NameEqual("John".to_string());
+//! let filter = PersonFilter::NameEqual("John".to_string());
 //! let john: Vec<Person> = person_table.select(filter.into()).unwrap();
 //! assert!(john.len() == 0);
 //!
@@ -100,8 +100,52 @@
 //!
 //! ```
 
+mod column;
+mod connection;
+mod dialect;
+mod error;
+#[cfg(feature = "json")]
+pub mod json;
+mod page;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+mod table;
+mod temporal;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod value;
+
+pub use column::Column;
+#[cfg(feature = "sqlx")]
+pub use connection::AsyncConnection;
+pub use connection::Connection;
+pub use dialect::Dialect;
+pub use error::{Error, Result};
+#[cfg(feature = "json")]
+pub use json::{JsonStorage, JsonValue};
+pub use page::Page;
+pub use table::Table;
+pub use temporal::{Temporal, UnixStorage};
+#[cfg(feature = "uuid")]
+pub use self::uuid::{BlobStorage, UuidVersion};
+pub use value::{FromRow, FromSqlValue, ToSqlValue, Value};
 pub use worm_macro::*;
 
+/// Database drivers named by the generated constructors, re-exported so that a crate deriving a
+/// table does not need its own dependency on the matching version
+pub mod driver {
+	#[cfg(feature = "postgres")]
+	pub use ::postgres;
+	#[cfg(feature = "rusqlite")]
+	pub use ::rusqlite;
+	#[cfg(feature = "sqlx")]
+	pub use ::sqlx;
+}
+
 #[allow(dead_code)]
 fn nothing_here() {
 	todo!()
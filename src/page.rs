@@ -0,0 +1,23 @@
+/// Page of rows returned by [`Table::select_page`](crate::Table::select_page)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page<T> {
+	/// The rows of the page
+	pub rows: Vec<T>,
+	/// Number of the page, starting from 0
+	pub page: usize,
+	/// Maximum number of rows per page
+	pub per_page: usize,
+	/// Number of rows matching the filter, across all pages
+	pub total: usize,
+}
+
+impl<T> Page<T> {
+	/// Number of pages needed to hold every matching row
+	pub fn page_count(&self) -> usize {
+		if self.per_page == 0 {
+			0
+		} else {
+			self.total.div_ceil(self.per_page)
+		}
+	}
+}
@@ -0,0 +1,162 @@
+//! Execution of the generated statements through [postgres](https://docs.rs/postgres)
+
+use crate::value::signed;
+use crate::{Error, Result, Value};
+use postgres::error::SqlState;
+use postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error as StdError;
+
+impl ToSql for Value {
+	fn to_sql(
+		&self,
+		ty: &Type,
+		out: &mut postgres::types::private::BytesMut,
+	) -> std::result::Result<IsNull, Box<dyn StdError + Sync + Send>> {
+		match self {
+			Value::Null => Ok(IsNull::Yes),
+			Value::Boolean(v) => match *ty {
+				Type::BOOL => v.to_sql(ty, out),
+				_ => integer_to_sql((*v).into(), ty, out),
+			},
+			Value::Integer(v) => integer_to_sql(*v, ty, out),
+			Value::Unsigned(v) => integer_to_sql(signed(*v)?, ty, out),
+			Value::Real(v) => match *ty {
+				Type::FLOAT4 => (*v as f32).to_sql(ty, out),
+				_ => v.to_sql(ty, out),
+			},
+			Value::Text(v) => v.as_str().to_sql(ty, out),
+			Value::Blob(v) => v.as_slice().to_sql(ty, out),
+			Value::Json(v) => {
+				// The binary format of JSONB is the text prefixed by a version number
+				if *ty == Type::JSONB {
+					out.extend_from_slice(&[1]);
+				}
+				out.extend_from_slice(v.as_bytes());
+				Ok(IsNull::No)
+			}
+		}
+	}
+	
+	fn accepts(_ty: &Type) -> bool {
+		true
+	}
+	
+	to_sql_checked!();
+}
+
+/// Postgres integers have a fixed width, so the value is narrowed to the type of the column
+fn integer_to_sql(
+	v: i64,
+	ty: &Type,
+	out: &mut postgres::types::private::BytesMut,
+) -> std::result::Result<IsNull, Box<dyn StdError + Sync + Send>> {
+	match *ty {
+		Type::INT2 => i16::try_from(v)?.to_sql(ty, out),
+		Type::INT4 => i32::try_from(v)?.to_sql(ty, out),
+		_ => v.to_sql(ty, out),
+	}
+}
+
+impl<'a> FromSql<'a> for Value {
+	fn from_sql(ty: &Type, raw: &'a [u8]) -> std::result::Result<Self, Box<dyn StdError + Sync + Send>> {
+		match *ty {
+			Type::BOOL => Ok(Value::Boolean(bool::from_sql(ty, raw)?)),
+			Type::INT2 => Ok(Value::Integer(i16::from_sql(ty, raw)?.into())),
+			Type::INT4 => Ok(Value::Integer(i32::from_sql(ty, raw)?.into())),
+			Type::INT8 => Ok(Value::Integer(i64::from_sql(ty, raw)?)),
+			Type::FLOAT4 => Ok(Value::Real(f32::from_sql(ty, raw)?.into())),
+			Type::FLOAT8 => Ok(Value::Real(f64::from_sql(ty, raw)?)),
+			Type::BYTEA => Ok(Value::Blob(Vec::<u8>::from_sql(ty, raw)?)),
+			Type::JSON => Ok(Value::Json(String::from_utf8(raw.to_vec())?)),
+			Type::JSONB => match raw.split_first() {
+				Some((1, json)) => Ok(Value::Json(String::from_utf8(json.to_vec())?)),
+				_ => Err("Unsupported JSONB version".into()),
+			},
+			_ => Ok(Value::Text(String::from_sql(ty, raw)?)),
+		}
+	}
+	
+	fn from_sql_null(_ty: &Type) -> std::result::Result<Self, Box<dyn StdError + Sync + Send>> {
+		Ok(Value::Null)
+	}
+	
+	fn accepts(ty: &Type) -> bool {
+		matches!(
+			*ty,
+			Type::BOOL
+				| Type::INT2 | Type::INT4 | Type::INT8
+				| Type::FLOAT4 | Type::FLOAT8
+				| Type::BYTEA
+				| Type::JSON | Type::JSONB
+		) || <String as FromSql>::accepts(ty)
+	}
+}
+
+impl From<postgres::Error> for Error {
+	fn from(e: postgres::Error) -> Self {
+		if let Some(db_error) = e.as_db_error() {
+			let message = db_error.message().to_string();
+			match *db_error.code() {
+				SqlState::UNIQUE_VIOLATION
+					if db_error.constraint().is_some_and(|c| c.ends_with("_pkey")) =>
+				{
+					return Error::PrimaryKeyViolation(message)
+				}
+				SqlState::UNIQUE_VIOLATION => return Error::UniqueViolation(message),
+				SqlState::NOT_NULL_VIOLATION => return Error::NotNullViolation(message),
+				_ => {}
+			}
+		}
+		if let Some(Error::Conversion(message)) = e.source().and_then(|source| source.downcast_ref::<Error>()) {
+			return Error::Conversion(message.clone());
+		}
+		if e.source().is_some_and(|source| source.is::<postgres::types::WrongType>()) {
+			return Error::Conversion(e.to_string());
+		}
+		Error::Driver(Box::new(e))
+	}
+}
+
+fn params(params: &[Value]) -> Vec<&(dyn ToSql + Sync)> {
+	params.iter().map(|v| v as &(dyn ToSql + Sync)).collect()
+}
+
+/// Execute a statement, returning the number of rows affected
+pub fn execute(client: &mut postgres::Client, statement: &str, values: &[Value]) -> Result<usize> {
+	Ok(client.execute(statement, &params(values))? as usize)
+}
+
+/// Execute the statements in a transaction, preparing a statement once for consecutive executions
+pub fn execute_many(client: &mut postgres::Client, statements: &[(String, Vec<Value>)]) -> Result<usize> {
+	let mut transaction = client.transaction()?;
+	let mut prepared: Option<(&str, postgres::Statement)> = None;
+	let mut count = 0;
+	for (statement, values) in statements {
+		let (text, prepared_statement) = match prepared.take() {
+			Some((text, prepared_statement)) if text == statement => (text, prepared_statement),
+			_ => (statement.as_str(), transaction.prepare(statement)?),
+		};
+		count += transaction.execute(&prepared_statement, &params(values))? as usize;
+		prepared = Some((text, prepared_statement));
+	}
+	transaction.commit()?;
+	Ok(count)
+}
+
+/// Execute a query, returning the values of every row
+pub fn query(
+	client: &mut postgres::Client,
+	statement: &str,
+	values: &[Value],
+) -> Result<Vec<Vec<Value>>> {
+	let rows = client.query(statement, &params(values))?;
+	let mut result = vec![];
+	for row in rows.iter() {
+		result.push(
+			(0..row.len())
+				.map(|i| row.try_get(i))
+				.collect::<std::result::Result<Vec<Value>, postgres::Error>>()?,
+		);
+	}
+	Ok(result)
+}
@@ -0,0 +1,110 @@
+//! Execution of the generated statements through [rusqlite](https://docs.rs/rusqlite)
+
+use crate::value::signed;
+use crate::{Error, Result, Value};
+use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+impl ToSql for Value {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		match self {
+			Value::Null => Ok(ToSqlOutput::Borrowed(ValueRef::Null)),
+			Value::Boolean(v) => Ok(ToSqlOutput::Borrowed(ValueRef::Integer((*v).into()))),
+			Value::Integer(v) => Ok(ToSqlOutput::Borrowed(ValueRef::Integer(*v))),
+			Value::Unsigned(v) => signed(*v)
+				.map(|v| ToSqlOutput::Owned(rusqlite::types::Value::Integer(v)))
+				.map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e))),
+			Value::Real(v) => Ok(ToSqlOutput::Borrowed(ValueRef::Real(*v))),
+			Value::Text(v) | Value::Json(v) => Ok(ToSqlOutput::Borrowed(ValueRef::Text(v.as_bytes()))),
+			Value::Blob(v) => Ok(ToSqlOutput::Borrowed(ValueRef::Blob(v))),
+		}
+	}
+}
+
+impl FromSql for Value {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		match value {
+			ValueRef::Null => Ok(Value::Null),
+			ValueRef::Integer(v) => Ok(Value::Integer(v)),
+			ValueRef::Real(v) => Ok(Value::Real(v)),
+			ValueRef::Text(_) => String::column_result(value).map(Value::Text),
+			ValueRef::Blob(v) => Ok(Value::Blob(v.to_vec())),
+		}
+	}
+}
+
+impl From<rusqlite::Error> for Error {
+	fn from(e: rusqlite::Error) -> Self {
+		use rusqlite::ffi;
+		
+		match e {
+			rusqlite::Error::SqliteFailure(failure, message) => {
+				let message = message.unwrap_or_else(|| failure.to_string());
+				match failure.extended_code {
+					ffi::SQLITE_CONSTRAINT_UNIQUE => Error::UniqueViolation(message),
+					ffi::SQLITE_CONSTRAINT_PRIMARYKEY => Error::PrimaryKeyViolation(message),
+					ffi::SQLITE_CONSTRAINT_NOTNULL => Error::NotNullViolation(message),
+					_ => Error::Driver(Box::new(rusqlite::Error::SqliteFailure(
+						failure,
+						Some(message),
+					))),
+				}
+			}
+			rusqlite::Error::QueryReturnedNoRows => Error::NotFound,
+			rusqlite::Error::ToSqlConversionFailure(e) => match e.downcast::<Error>() {
+				Ok(e) => *e,
+				Err(e) => Error::Conversion(e.to_string()),
+			},
+			e @ (rusqlite::Error::FromSqlConversionFailure(..)
+			| rusqlite::Error::IntegralValueOutOfRange(..)
+			| rusqlite::Error::InvalidColumnType(..)) => Error::Conversion(e.to_string()),
+			e => Error::Driver(Box::new(e)),
+		}
+	}
+}
+
+/// Execute a statement, returning the number of rows affected
+pub fn execute(conn: &rusqlite::Connection, statement: &str, params: &[Value]) -> Result<usize> {
+	Ok(conn.execute(statement, rusqlite::params_from_iter(params.iter()))?)
+}
+
+/// Execute the statements in a transaction, unless the caller already opened one
+///
+/// The prepared statements are cached by the connection, a statement repeated for every chunk of
+/// rows is only prepared once.
+pub fn execute_many(conn: &rusqlite::Connection, statements: &[(String, Vec<Value>)]) -> Result<usize> {
+	let transaction = if conn.is_autocommit() {
+		Some(conn.unchecked_transaction()?)
+	} else {
+		None
+	};
+	let mut count = 0;
+	for (statement, params) in statements {
+		count += conn
+			.prepare_cached(statement)?
+			.execute(rusqlite::params_from_iter(params.iter()))?;
+	}
+	if let Some(transaction) = transaction {
+		transaction.commit()?;
+	}
+	Ok(count)
+}
+
+/// Execute a query, returning the values of every row
+pub fn query(
+	conn: &rusqlite::Connection,
+	statement: &str,
+	params: &[Value],
+) -> Result<Vec<Vec<Value>>> {
+	let mut s = conn.prepare(statement)?;
+	let columns = s.column_count();
+	let mut rows = s.query(rusqlite::params_from_iter(params.iter()))?;
+	let mut result = vec![];
+	while let Some(row) = rows.next()? {
+		result.push(
+			(0..columns)
+				.map(|i| row.get(i))
+				.collect::<rusqlite::Result<Vec<Value>>>()?,
+		);
+	}
+	Ok(result)
+}
@@ -0,0 +1,222 @@
+//! Asynchronous execution of the generated statements through [sqlx](https://docs.rs/sqlx)
+
+use crate::value::signed;
+use crate::{Error, Result, Value};
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, Postgres};
+use sqlx::sqlite::Sqlite;
+use sqlx::{Row, TypeInfo, ValueRef};
+
+/// JSON document bound to a JSONB parameter
+struct Jsonb<'a>(&'a str);
+
+impl sqlx::Type<Postgres> for Jsonb<'_> {
+	fn type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(sqlx::postgres::types::Oid(3802))
+	}
+}
+
+impl sqlx::Encode<'_, Postgres> for Jsonb<'_> {
+	fn encode_by_ref(
+		&self,
+		buf: &mut PgArgumentBuffer,
+	) -> std::result::Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+		// The binary format of JSONB is the text prefixed by a version number
+		buf.push(1);
+		buf.extend_from_slice(self.0.as_bytes());
+		Ok(sqlx::encode::IsNull::No)
+	}
+}
+
+/// NULL parameter whose type is inferred by Postgres from the statement
+struct Unspecified;
+
+impl sqlx::Type<Postgres> for Unspecified {
+	fn type_info() -> PgTypeInfo {
+		PgTypeInfo::with_oid(sqlx::postgres::types::Oid(0))
+	}
+}
+
+impl sqlx::Encode<'_, Postgres> for Unspecified {
+	fn encode_by_ref(
+		&self,
+		_buf: &mut PgArgumentBuffer,
+	) -> std::result::Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+		Ok(sqlx::encode::IsNull::Yes)
+	}
+}
+
+impl From<sqlx::Error> for Error {
+	fn from(e: sqlx::Error) -> Self {
+		match e {
+			sqlx::Error::Database(e) => {
+				let message = e.message().to_string();
+				let primary_key = e.code().as_deref() == Some("1555")
+					|| e.constraint().is_some_and(|c| c.ends_with("_pkey"));
+				match e.kind() {
+					sqlx::error::ErrorKind::UniqueViolation if primary_key => {
+						Error::PrimaryKeyViolation(message)
+					}
+					sqlx::error::ErrorKind::UniqueViolation => Error::UniqueViolation(message),
+					sqlx::error::ErrorKind::NotNullViolation => Error::NotNullViolation(message),
+					_ => Error::Driver(Box::new(sqlx::Error::Database(e))),
+				}
+			}
+			sqlx::Error::RowNotFound => Error::NotFound,
+			e @ (sqlx::Error::ColumnDecode { .. } | sqlx::Error::Decode(_) | sqlx::Error::Encode(_)) => {
+				Error::Conversion(e.to_string())
+			}
+			e => Error::Driver(Box::new(e)),
+		}
+	}
+}
+
+/// Execute a statement on an SQLite pool, returning the number of rows affected
+pub async fn execute_sqlite(pool: &sqlx::SqlitePool, statement: &str, params: &[Value]) -> Result<usize> {
+	let r = bind_sqlite(sqlx::query(statement), params)?.execute(pool).await?;
+	Ok(r.rows_affected() as usize)
+}
+
+/// Execute the statements in a transaction of an SQLite pool, the connection caching the prepared
+/// statements
+pub async fn execute_many_sqlite(pool: &sqlx::SqlitePool, statements: &[(String, Vec<Value>)]) -> Result<usize> {
+	let mut transaction = pool.begin().await?;
+	let mut count = 0;
+	for (statement, params) in statements {
+		let r = bind_sqlite(sqlx::query(statement), params)?.execute(&mut *transaction).await?;
+		count += r.rows_affected() as usize;
+	}
+	transaction.commit().await?;
+	Ok(count)
+}
+
+/// Execute a query on an SQLite pool, returning the values of every row
+pub async fn query_sqlite(
+	pool: &sqlx::SqlitePool,
+	statement: &str,
+	params: &[Value],
+) -> Result<Vec<Vec<Value>>> {
+	let rows = bind_sqlite(sqlx::query(statement), params)?.fetch_all(pool).await?;
+	let mut result = vec![];
+	for row in rows.iter() {
+		let mut values = vec![];
+		for i in 0..row.columns().len() {
+			let raw = row.try_get_raw(i)?;
+			let value = if raw.is_null() {
+				Value::Null
+			} else {
+				match raw.type_info().name() {
+					"INTEGER" => Value::Integer(row.try_get(i)?),
+					"REAL" => Value::Real(row.try_get(i)?),
+					"TEXT" => Value::Text(row.try_get(i)?),
+					"BLOB" => Value::Blob(row.try_get(i)?),
+					name => return Err(Error::Conversion(format!("Unsupported column type {}", name))),
+				}
+			};
+			values.push(value);
+		}
+		result.push(values);
+	}
+	Ok(result)
+}
+
+fn bind_sqlite<'q>(
+	mut query: sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+	params: &'q [Value],
+) -> Result<sqlx::query::Query<'q, Sqlite, sqlx::sqlite::SqliteArguments<'q>>> {
+	for param in params {
+		query = match param {
+			Value::Null => query.bind(None::<i64>),
+			Value::Boolean(v) => query.bind(*v),
+			Value::Integer(v) => query.bind(*v),
+			Value::Unsigned(v) => query.bind(signed(*v)?),
+			Value::Real(v) => query.bind(*v),
+			Value::Text(v) | Value::Json(v) => query.bind(v.as_str()),
+			Value::Blob(v) => query.bind(v.as_slice()),
+		};
+	}
+	Ok(query)
+}
+
+/// Execute a statement on a Postgres pool, returning the number of rows affected
+pub async fn execute_postgres(pool: &sqlx::PgPool, statement: &str, params: &[Value]) -> Result<usize> {
+	let r = bind_postgres(sqlx::query(statement), params)?.execute(pool).await?;
+	Ok(r.rows_affected() as usize)
+}
+
+/// Execute the statements in a transaction of a Postgres pool, the connection caching the prepared
+/// statements
+pub async fn execute_many_postgres(pool: &sqlx::PgPool, statements: &[(String, Vec<Value>)]) -> Result<usize> {
+	let mut transaction = pool.begin().await?;
+	let mut count = 0;
+	for (statement, params) in statements {
+		let r = bind_postgres(sqlx::query(statement), params)?.execute(&mut *transaction).await?;
+		count += r.rows_affected() as usize;
+	}
+	transaction.commit().await?;
+	Ok(count)
+}
+
+/// Execute a query on a Postgres pool, returning the values of every row
+pub async fn query_postgres(
+	pool: &sqlx::PgPool,
+	statement: &str,
+	params: &[Value],
+) -> Result<Vec<Vec<Value>>> {
+	let rows = bind_postgres(sqlx::query(statement), params)?.fetch_all(pool).await?;
+	let mut result = vec![];
+	for row in rows.iter() {
+		let mut values = vec![];
+		for i in 0..row.columns().len() {
+			let raw = row.try_get_raw(i)?;
+			let value = if raw.is_null() {
+				Value::Null
+			} else {
+				match raw.type_info().name() {
+					"BOOL" => Value::Boolean(row.try_get(i)?),
+					"INT2" => Value::Integer(row.try_get::<i16, _>(i)?.into()),
+					"INT4" => Value::Integer(row.try_get::<i32, _>(i)?.into()),
+					"INT8" => Value::Integer(row.try_get(i)?),
+					"FLOAT4" => Value::Real(row.try_get::<f32, _>(i)?.into()),
+					"FLOAT8" => Value::Real(row.try_get(i)?),
+					"BYTEA" => Value::Blob(row.try_get(i)?),
+					"JSON" | "JSONB" => Value::Json(json(raw)?),
+					_ => Value::Text(row.try_get(i)?),
+				}
+			};
+			values.push(value);
+		}
+		result.push(values);
+	}
+	Ok(result)
+}
+
+/// Text of a JSON or JSONB value, the binary format of JSONB prefixing it with a version number
+fn json(raw: sqlx::postgres::PgValueRef<'_>) -> Result<String> {
+	let jsonb = raw.type_info().name() == "JSONB" && raw.format() == sqlx::postgres::PgValueFormat::Binary;
+	let bytes = raw.as_bytes().map_err(|e| Error::Conversion(e.to_string()))?;
+	let text = match bytes.split_first() {
+		Some((1, text)) if jsonb => text,
+		_ if jsonb => return Err(Error::Conversion("Unsupported JSONB version".to_string())),
+		_ => bytes,
+	};
+	String::from_utf8(text.to_vec()).map_err(|e| Error::Conversion(e.to_string()))
+}
+
+fn bind_postgres<'q>(
+	mut query: sqlx::query::Query<'q, Postgres, sqlx::postgres::PgArguments>,
+	params: &'q [Value],
+) -> Result<sqlx::query::Query<'q, Postgres, sqlx::postgres::PgArguments>> {
+	for param in params {
+		query = match param {
+			Value::Null => query.bind(None::<Unspecified>),
+			Value::Boolean(v) => query.bind(*v),
+			Value::Integer(v) => query.bind(*v),
+			Value::Unsigned(v) => query.bind(signed(*v)?),
+			Value::Real(v) => query.bind(*v),
+			Value::Text(v) => query.bind(v.as_str()),
+			Value::Blob(v) => query.bind(v.as_slice()),
+			Value::Json(v) => query.bind(Jsonb(v)),
+		};
+	}
+	Ok(query)
+}
@@ -0,0 +1,67 @@
+use crate::{Page, Result};
+
+/// Operations on an SQL table, implemented by the table struct generated by `#[derive(Table)]`
+///
+/// The generated struct also exposes these operations as inherent methods. The trait allows
+/// writing code that works over any worm table, e.g. a repository or a test fixture.
+pub trait Table {
+	/// The struct stored in the table
+	type Row;
+	/// Value returned by [`Table::insert`], the generated key of an `auto = true` or a
+	/// `generate = ...` primary key, or `()`
+	type Id;
+	/// Filtering of the rows, aka WHERE in SQL queries
+	type Filter;
+	/// Parameters of [`Table::select`]
+	type Select: From<Self::Filter>;
+	/// Parameters of [`Table::select_one`]
+	type SelectOne: From<Self::Filter>;
+	/// Parameters of [`Table::count`]
+	type Count: From<Self::Filter>;
+	/// Parameters of [`Table::delete`]
+	type Delete: From<Self::Filter>;
+	
+	fn create_table(&self) -> Result<()>;
+	
+	fn delete_table(&self) -> Result<()>;
+	
+	fn insert(&self, row: &Self::Row) -> Result<Self::Id>;
+	
+	/// Insert `rows` in a single transaction, returning the number of rows inserted
+	fn insert_many(&self, rows: &[Self::Row]) -> Result<usize>;
+	
+	/// Insert the rows of an iterator in a single transaction, see [`Table::insert_many`]
+	fn insert_iter<'r, I: IntoIterator<Item = &'r Self::Row>>(&self, rows: I) -> Result<usize>
+	where
+		Self::Row: 'r;
+	
+	/// Insert `row`, returning the row as stored including its generated key
+	fn insert_returning(&self, row: &Self::Row) -> Result<Self::Row>;
+	
+	/// Update the row sharing the primary key of `row`, returning the number of rows updated
+	fn update_by_id(&self, row: &Self::Row) -> Result<usize>;
+	
+	/// Update the row of `from` with the values of `to`, returning the number of rows updated
+	///
+	/// The row is matched on the primary key of `from`, or on every column of a table without
+	/// primary key.
+	fn update_to(&self, from: &Self::Row, to: &Self::Row) -> Result<usize>;
+	
+	fn select(&self, select: Self::Select) -> Result<Vec<Self::Row>>;
+	
+	fn select_one(&self, select_one: Self::SelectOne) -> Result<Option<Self::Row>>;
+	
+	fn select_all(&self) -> Result<Vec<Self::Row>>;
+	
+	/// Select the page `page`, numbered from 0, of `per_page` rows, along with the number of rows
+	/// matching the filter of `select`
+	fn select_page(&self, select: Self::Select, page: usize, per_page: usize) -> Result<Page<Self::Row>>;
+	
+	fn count(&self, count: Self::Count) -> Result<usize>;
+	
+	fn count_all(&self) -> Result<usize>;
+	
+	fn delete(&self, delete: Self::Delete) -> Result<()>;
+	
+	fn delete_all(&self) -> Result<()>;
+}
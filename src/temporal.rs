@@ -0,0 +1,181 @@
+//! Date and time columns, stored as ISO-8601 text or as seconds since the unix epoch
+
+use crate::{FromSqlValue, Result, Value};
+
+/// A date, or a date and time, stored by a `#[date]` or `#[datetime]` column
+///
+/// The text is ISO-8601 in UTC with a fixed width, so that the text columns sort and compare like
+/// the instants they hold. The unix storage keeps whole seconds, dates being stored at midnight UTC.
+pub trait Temporal: Sized {
+	fn to_text(&self) -> String;
+	fn from_text(text: &str) -> Result<Self>;
+	fn to_unix(&self) -> i64;
+	fn from_unix(seconds: i64) -> Result<Self>;
+}
+
+/// Conversion of the fields of the columns declared with `storage = "unix"`
+pub trait UnixStorage: Sized {
+	fn to_unix_value(&self) -> Value;
+	fn from_unix_value(value: Value) -> Result<Self>;
+}
+
+impl<T: Temporal> UnixStorage for T {
+	fn to_unix_value(&self) -> Value {
+		Value::Integer(self.to_unix())
+	}
+	
+	fn from_unix_value(value: Value) -> Result<Self> {
+		T::from_unix(i64::from_sql_value(value)?)
+	}
+}
+
+impl<T: Temporal> UnixStorage for Option<T> {
+	fn to_unix_value(&self) -> Value {
+		match self {
+			Some(v) => v.to_unix_value(),
+			None => Value::Null,
+		}
+	}
+	
+	fn from_unix_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Null => Ok(None),
+			value => T::from_unix_value(value).map(Some),
+		}
+	}
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! temporal {
+	($ty:ty) => {
+		impl $crate::ToSqlValue for $ty {
+			fn to_sql_value(&self) -> $crate::Value {
+				$crate::Value::Text(self.to_text())
+			}
+		}
+		
+		impl $crate::FromSqlValue for $ty {
+			fn from_sql_value(value: $crate::Value) -> $crate::Result<Self> {
+				match value {
+					$crate::Value::Text(v) => <$ty>::from_text(&v),
+					value => Err($crate::Error::Conversion(format!(
+						"Unable to convert {:?} to {}",
+						value,
+						stringify!($ty)
+					))),
+				}
+			}
+		}
+	};
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn invalid(value: impl std::fmt::Display, ty: &str) -> crate::Error {
+	crate::Error::Conversion(format!("{} is not a valid {}", value, ty))
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_types {
+	use super::{invalid, Temporal};
+	use crate::Result;
+	use chrono::{DateTime, NaiveDate, Utc};
+	
+	impl Temporal for DateTime<Utc> {
+		fn to_text(&self) -> String {
+			self.format("%Y-%m-%dT%H:%M:%S%.9fZ").to_string()
+		}
+		
+		fn from_text(text: &str) -> Result<Self> {
+			DateTime::parse_from_rfc3339(text)
+				.map(|v| v.with_timezone(&Utc))
+				.map_err(|_| invalid(text, "DateTime<Utc>"))
+		}
+		
+		fn to_unix(&self) -> i64 {
+			self.timestamp()
+		}
+		
+		fn from_unix(seconds: i64) -> Result<Self> {
+			DateTime::from_timestamp(seconds, 0).ok_or_else(|| invalid(seconds, "DateTime<Utc>"))
+		}
+	}
+	
+	impl Temporal for NaiveDate {
+		fn to_text(&self) -> String {
+			self.format("%Y-%m-%d").to_string()
+		}
+		
+		fn from_text(text: &str) -> Result<Self> {
+			NaiveDate::parse_from_str(text, "%Y-%m-%d").map_err(|_| invalid(text, "NaiveDate"))
+		}
+		
+		fn to_unix(&self) -> i64 {
+			self.and_time(chrono::NaiveTime::MIN).and_utc().timestamp()
+		}
+		
+		fn from_unix(seconds: i64) -> Result<Self> {
+			DateTime::from_timestamp(seconds, 0)
+				.map(|v| v.date_naive())
+				.ok_or_else(|| invalid(seconds, "NaiveDate"))
+		}
+	}
+	
+	temporal!(DateTime<Utc>);
+	temporal!(NaiveDate);
+}
+
+#[cfg(feature = "time")]
+mod time_types {
+	use super::{invalid, Temporal};
+	use crate::Result;
+	use time::format_description::well_known::Rfc3339;
+	use time::macros::format_description;
+	use time::{Date, OffsetDateTime, UtcOffset};
+	
+	const DATE_TIME: &[time::format_description::FormatItem<'_>] =
+		format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:9]Z");
+	const DATE: &[time::format_description::FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+	
+	impl Temporal for OffsetDateTime {
+		fn to_text(&self) -> String {
+			self.to_offset(UtcOffset::UTC)
+				.format(DATE_TIME)
+				.expect("A date and time in UTC can always be formatted")
+		}
+		
+		fn from_text(text: &str) -> Result<Self> {
+			OffsetDateTime::parse(text, &Rfc3339).map_err(|_| invalid(text, "OffsetDateTime"))
+		}
+		
+		fn to_unix(&self) -> i64 {
+			self.unix_timestamp()
+		}
+		
+		fn from_unix(seconds: i64) -> Result<Self> {
+			OffsetDateTime::from_unix_timestamp(seconds).map_err(|_| invalid(seconds, "OffsetDateTime"))
+		}
+	}
+	
+	impl Temporal for Date {
+		fn to_text(&self) -> String {
+			self.format(DATE).expect("A date can always be formatted")
+		}
+		
+		fn from_text(text: &str) -> Result<Self> {
+			Date::parse(text, DATE).map_err(|_| invalid(text, "Date"))
+		}
+		
+		fn to_unix(&self) -> i64 {
+			self.midnight().assume_utc().unix_timestamp()
+		}
+		
+		fn from_unix(seconds: i64) -> Result<Self> {
+			OffsetDateTime::from_unix_timestamp(seconds)
+				.map(|v| v.date())
+				.map_err(|_| invalid(seconds, "Date"))
+		}
+	}
+	
+	temporal!(OffsetDateTime);
+	temporal!(Date);
+}
@@ -0,0 +1,74 @@
+//! Uuid columns, stored as hyphenated text or as their 16 bytes
+
+use crate::{Error, FromSqlValue, Result, ToSqlValue, Value};
+use ::uuid::Uuid;
+
+/// Version of the uuid generated on insert for a nil `#[uuid(generate = ...)]` primary key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UuidVersion {
+	/// Random
+	V4,
+	/// Ordered by creation time
+	V7,
+}
+
+impl UuidVersion {
+	/// The id itself, or a newly generated one when it is nil
+	pub fn or_generate(self, id: &Uuid) -> Uuid {
+		match self {
+			_ if !id.is_nil() => *id,
+			UuidVersion::V4 => Uuid::new_v4(),
+			UuidVersion::V7 => Uuid::now_v7(),
+		}
+	}
+}
+
+/// Conversion of the fields of the columns declared with `storage = "blob"`
+pub trait BlobStorage: Sized {
+	fn to_blob_value(&self) -> Value;
+	fn from_blob_value(value: Value) -> Result<Self>;
+}
+
+impl BlobStorage for Uuid {
+	fn to_blob_value(&self) -> Value {
+		Value::Blob(self.as_bytes().to_vec())
+	}
+	
+	fn from_blob_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Blob(v) => Uuid::from_slice(&v).map_err(|e| Error::Conversion(e.to_string())),
+			value => Err(Error::Conversion(format!("Unable to convert {:?} to Uuid", value))),
+		}
+	}
+}
+
+impl BlobStorage for Option<Uuid> {
+	fn to_blob_value(&self) -> Value {
+		match self {
+			Some(v) => v.to_blob_value(),
+			None => Value::Null,
+		}
+	}
+	
+	fn from_blob_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Null => Ok(None),
+			value => Uuid::from_blob_value(value).map(Some),
+		}
+	}
+}
+
+impl ToSqlValue for Uuid {
+	fn to_sql_value(&self) -> Value {
+		Value::Text(self.hyphenated().to_string())
+	}
+}
+
+impl FromSqlValue for Uuid {
+	fn from_sql_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Text(v) => Uuid::parse_str(&v).map_err(|e| Error::Conversion(e.to_string())),
+			value => Err(Error::Conversion(format!("Unable to convert {:?} to Uuid", value))),
+		}
+	}
+}
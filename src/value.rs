@@ -0,0 +1,215 @@
+use crate::{Error, Result};
+
+/// A value bound as a parameter of a generated SQL statement, or read from a row
+///
+/// Filters never interpolate user supplied values into the statement text. Each value is instead
+/// collected, in placeholder order, as a `Value` and bound by the database wrapper.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+	Null,
+	Boolean(bool),
+	Integer(i64),
+	Unsigned(u64),
+	Real(f64),
+	Text(String),
+	Blob(Vec<u8>),
+	/// A JSON document, bound as text on SQLite and as JSONB on Postgres
+	Json(String),
+}
+
+/// Conversion of a field into the [`Value`] bound to a statement
+///
+/// Implemented along with [`FromSqlValue`], it stores a type of your own in a column declared with
+/// `#[column(type = "...")]`, the type nominating the SQL storage of the column:
+///
+/// ```
+/// use worm::{FromSqlValue, Result, ToSqlValue, Value};
+///
+/// #[derive(Clone)]
+/// struct Cents(i64);
+///
+/// impl ToSqlValue for Cents {
+///   fn to_sql_value(&self) -> Value {
+///     self.0.to_sql_value()
+///   }
+/// }
+///
+/// impl FromSqlValue for Cents {
+///   fn from_sql_value(value: Value) -> Result<Self> {
+///     i64::from_sql_value(value).map(Cents)
+///   }
+/// }
+///
+/// #[derive(worm::Table)]
+/// struct Invoice {
+///   #[integer(primary = true)]
+///   id: u32,
+///   #[column(type = "integer")]
+///   total: Cents,
+/// }
+/// ```
+pub trait ToSqlValue {
+	fn to_sql_value(&self) -> Value;
+}
+
+/// Conversion of a [`Value`] read from a row into a field, see [`ToSqlValue`]
+pub trait FromSqlValue: Sized {
+	fn from_sql_value(value: Value) -> Result<Self>;
+}
+
+/// Construction of a struct from the values of a selected row
+pub trait FromRow: Sized {
+	fn from_row(row: Vec<Value>) -> Result<Self>;
+}
+
+impl<T: ToSqlValue + ?Sized> ToSqlValue for &T {
+	fn to_sql_value(&self) -> Value {
+		(**self).to_sql_value()
+	}
+}
+
+impl<T: ToSqlValue> ToSqlValue for Option<T> {
+	fn to_sql_value(&self) -> Value {
+		match self {
+			Some(v) => v.to_sql_value(),
+			None => Value::Null,
+		}
+	}
+}
+
+impl<T: FromSqlValue> FromSqlValue for Option<T> {
+	fn from_sql_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Null => Ok(None),
+			value => T::from_sql_value(value).map(Some),
+		}
+	}
+}
+
+impl ToSqlValue for str {
+	fn to_sql_value(&self) -> Value {
+		Value::Text(self.to_string())
+	}
+}
+
+impl ToSqlValue for String {
+	fn to_sql_value(&self) -> Value {
+		Value::Text(self.clone())
+	}
+}
+
+impl FromSqlValue for String {
+	fn from_sql_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Text(v) => Ok(v),
+			value => Err(unexpected(&value, "String")),
+		}
+	}
+}
+
+macro_rules! integer {
+	($ty:ty, $variant:ident, $wide:ty) => {
+		impl ToSqlValue for $ty {
+			fn to_sql_value(&self) -> Value {
+				// Widening to the 64-bit type of the variant, never truncates
+				Value::$variant(*self as $wide)
+			}
+		}
+		
+		impl FromSqlValue for $ty {
+			fn from_sql_value(value: Value) -> Result<Self> {
+				let converted = match &value {
+					Value::Integer(v) => <$ty>::try_from(*v).ok(),
+					Value::Unsigned(v) => <$ty>::try_from(*v).ok(),
+					_ => return Err(unexpected(&value, stringify!($ty))),
+				};
+				converted.ok_or_else(|| {
+					Error::Conversion(format!("{:?} is out of range for {}", value, stringify!($ty)))
+				})
+			}
+		}
+	};
+}
+
+integer!(i8, Integer, i64);
+integer!(i16, Integer, i64);
+integer!(i32, Integer, i64);
+integer!(i64, Integer, i64);
+integer!(isize, Integer, i64);
+integer!(u8, Integer, i64);
+integer!(u16, Integer, i64);
+integer!(u32, Integer, i64);
+integer!(u64, Unsigned, u64);
+integer!(usize, Unsigned, u64);
+
+/// Narrow an unsigned value to the signed 64-bit integer stored by the databases
+#[cfg(any(feature = "rusqlite", feature = "postgres", feature = "sqlx"))]
+pub(crate) fn signed(v: u64) -> Result<i64> {
+	i64::try_from(v).map_err(|_| {
+		Error::Conversion(format!("{} overflows a signed 64-bit INTEGER column (max {})", v, i64::MAX))
+	})
+}
+
+impl ToSqlValue for bool {
+	fn to_sql_value(&self) -> Value {
+		Value::Boolean(*self)
+	}
+}
+
+impl FromSqlValue for bool {
+	fn from_sql_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Boolean(v) => Ok(v),
+			Value::Integer(v) => Ok(v != 0),
+			value => Err(unexpected(&value, "bool")),
+		}
+	}
+}
+
+macro_rules! real {
+	($ty:ty) => {
+		impl ToSqlValue for $ty {
+			fn to_sql_value(&self) -> Value {
+				Value::Real((*self).into())
+			}
+		}
+		
+		impl FromSqlValue for $ty {
+			fn from_sql_value(value: Value) -> Result<Self> {
+				match value {
+					Value::Real(v) => Ok(v as $ty),
+					Value::Integer(v) => Ok(v as $ty),
+					value => Err(unexpected(&value, stringify!($ty))),
+				}
+			}
+		}
+	};
+}
+
+real!(f32);
+real!(f64);
+
+impl ToSqlValue for [u8] {
+	fn to_sql_value(&self) -> Value {
+		Value::Blob(self.to_vec())
+	}
+}
+
+impl ToSqlValue for Vec<u8> {
+	fn to_sql_value(&self) -> Value {
+		Value::Blob(self.clone())
+	}
+}
+
+impl FromSqlValue for Vec<u8> {
+	fn from_sql_value(value: Value) -> Result<Self> {
+		match value {
+			Value::Blob(v) => Ok(v),
+			value => Err(unexpected(&value, "Vec<u8>")),
+		}
+	}
+}
+
+fn unexpected(value: &Value, ty: &str) -> Error {
+	Error::Conversion(format!("Unable to convert {:?} to {}", value, ty))
+}
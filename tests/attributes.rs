@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+/// A customer of the shop
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Customer {
+	/// Identifier of the customer
+	#[integer(primary = true)]
+	#[allow(dead_code)]
+	id: i64,
+	/// Unique e-mail address, with an inferred type
+	#[worm(unique = true, column = "mail")]
+	email: String,
+	#[doc = "Display name"]
+	name: String,
+	/// Cache computed by the application, never persisted
+	#[worm(skip)]
+	cache: HashMap<String, u32>,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Customer::create_table(), "CREATE TABLE IF NOT EXISTS Customer ( id INTEGER NOT NULL PRIMARY KEY, mail TEXT UNIQUE, name TEXT )");
+	assert_eq!(Customer::insert(), "INSERT INTO Customer (id, mail, name) VALUES (?1, ?2, ?3)");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Customer::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let mut cache = HashMap::new();
+	cache.insert(String::from("orders"), 3);
+	db.insert(&Customer { id: 1, email: String::from("ann@example.com"), name: String::from("Ann"), cache }).unwrap();
+	
+	let ann = db.select_one(CustomerFilter::EmailEqual(String::from("ann@example.com")).into()).unwrap().unwrap();
+	assert_eq!(ann, Customer { id: 1, email: String::from("ann@example.com"), name: String::from("Ann"), cache: HashMap::new() });
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use worm::Dialect;
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Note {
+	#[integer(primary = true, auto = true)]
+	id: i64,
+	#[text()]
+	body: String,
+	#[integer(null = true)]
+	pinned: Option<u32>,
+}
+
+#[test]
+fn statements() {
+	assert_eq!(Note::create_table(), "CREATE TABLE IF NOT EXISTS Note ( id INTEGER PRIMARY KEY AUTOINCREMENT, body TEXT, pinned INTEGER NULL )");
+	assert_eq!(Note::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Note ( id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY, body TEXT, pinned BIGINT NULL )");
+	assert_eq!(Note::insert(), "INSERT INTO Note (body, pinned) VALUES (?1, ?2) RETURNING id");
+	assert_eq!(Note::insert_returning_for(Dialect::Postgres), "INSERT INTO Note (body, pinned) VALUES ($1, $2) RETURNING id, body, pinned");
+	assert_eq!(Note::update_by_id(), "UPDATE Note SET body = ?2, pinned = ?3 WHERE id = ?1");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Note::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	// The id of the struct is ignored on insert
+	let draft = Note { id: 0, body: String::from("draft"), pinned: None };
+	assert_eq!(db.insert(&draft).unwrap(), 1);
+	assert_eq!(db.insert(&draft).unwrap(), 2);
+	let stored = db.insert_returning(&Note { pinned: Some(1), ..draft.clone() }).unwrap();
+	assert_eq!(stored, Note { id: 3, body: String::from("draft"), pinned: Some(1) });
+	
+	db.update_by_id(&Note { body: String::from("final"), ..stored.clone() }).unwrap();
+	assert_eq!(db.count(NoteFilter::BodyEqual(String::from("final")).into()).unwrap(), 1);
+	assert_eq!(db.get_by_key(NoteKey { id: 3 }).unwrap().map(|n| n.body), Some(String::from("final")));
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn postgres() {
+	let mut client = common::postgres_client("worm_auto_key");
+	let db = PgNote::from_postgres(&mut client);
+	db.create_table().unwrap();
+	
+	let draft = PgNote { id: 0, body: String::from("draft") };
+	assert_eq!(db.insert(&draft).unwrap(), 1);
+	let stored = db.insert_returning(&draft).unwrap();
+	assert_eq!(stored.id, 2);
+	db.update_by_id(&PgNote { body: String::from("final"), ..stored }).unwrap();
+	assert_eq!(db.get_by_key(PgNoteKey { id: 2 }).unwrap().map(|n| n.body), Some(String::from("final")));
+	
+	db.delete_table().unwrap();
+}
+
+#[cfg(feature = "postgres")]
+#[derive(worm::Table)]
+pub struct PgNote {
+	#[integer(primary = true, auto = true)]
+	id: u32,
+	body: String,
+}
+
+#[cfg(feature = "sqlx")]
+#[tokio::test]
+async fn sqlx_sqlite() {
+	let pool = common::sqlite_pool().await;
+	let db = Note::from_sqlx_sqlite(&pool);
+	db.create_table().await.unwrap();
+	
+	let draft = Note { id: 0, body: String::from("draft"), pinned: None };
+	assert_eq!(db.insert(&draft).await.unwrap(), 1);
+	assert_eq!(db.insert_returning(&draft).await.unwrap(), Note { id: 2, ..draft });
+}
@@ -0,0 +1,53 @@
+use worm::Dialect;
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Article {
+	#[integer(primary = true)]
+	id: u32,
+	#[text()]
+	title: String,
+	#[integer()]
+	views: u32,
+	#[text(null = true)]
+	summary: Option<String>,
+}
+
+#[test]
+fn main() {
+	let changeset = ArticleChangeset { views: Some(3), summary: Some(None), ..Default::default() };
+	let (statement, params) = Article::update_where(&ArticleFilter::IdGreaterThan(1), &changeset).unwrap().unwrap();
+	assert_eq!(statement, "UPDATE Article SET views = ?1, summary = ?2 WHERE id > ?3");
+	assert_eq!(params, vec![worm::Value::Integer(3), worm::Value::Null, worm::Value::Integer(1)]);
+	
+	let changeset = ArticleChangeset { title: Some(String::from("Draft")), ..Default::default() };
+	let (statement, _) = Article::update_where_for(Dialect::Postgres, &ArticleFilter::IdEqual(2), &changeset).unwrap().unwrap();
+	assert_eq!(statement, "UPDATE Article SET title = $1 WHERE id = $2");
+	
+	assert!(Article::update_where(&ArticleFilter::IdEqual(2), &ArticleChangeset::default()).unwrap().is_none());
+	
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Article::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	db.insert_many(&[
+		Article { id: 1, title: String::from("One"), views: 10, summary: Some(String::from("first")) },
+		Article { id: 2, title: String::from("Two"), views: 20, summary: None },
+		Article { id: 3, title: String::from("Three"), views: 30, summary: None },
+	])
+	.unwrap();
+	
+	// Only the populated columns are written, the title of every row is kept
+	let changeset = ArticleChangeset { views: Some(0), summary: Some(Some(String::from("reset"))), ..Default::default() };
+	assert_eq!(db.update_where(ArticleFilter::ViewsGreaterThan(15), changeset).unwrap(), 2);
+	let clear = ArticleChangeset { summary: Some(None), ..Default::default() };
+	assert_eq!(db.update_where(ArticleFilter::IdEqual(1), clear).unwrap(), 1);
+	assert_eq!(db.update_where(ArticleFilter::IdEqual(1), ArticleChangeset::default()).unwrap(), 0);
+	
+	assert_eq!(
+		db.select_all().unwrap(),
+		vec![
+			Article { id: 1, title: String::from("One"), views: 10, summary: None },
+			Article { id: 2, title: String::from("Two"), views: 0, summary: Some(String::from("reset")) },
+			Article { id: 3, title: String::from("Three"), views: 0, summary: Some(String::from("reset")) },
+		]
+	);
+}
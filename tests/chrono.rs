@@ -0,0 +1,69 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Event {
+	#[integer(primary = true)]
+	id: u32,
+	#[datetime()]
+	at: DateTime<Utc>,
+	#[datetime(storage = "unix")]
+	logged: DateTime<Utc>,
+	#[date()]
+	day: NaiveDate,
+	#[date(storage = "unix", null = true)]
+	due: Option<NaiveDate>,
+	#[worm(storage = "unix")]
+	created: DateTime<Utc>,
+}
+
+#[test]
+fn main() {
+	assert_eq!(Event::create_table(), "CREATE TABLE IF NOT EXISTS Event ( id INTEGER NOT NULL PRIMARY KEY, at TEXT, logged INTEGER, day TEXT, due INTEGER NULL, created INTEGER )");
+	assert_eq!(Event::create_table_for(worm::Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Event ( id BIGINT NOT NULL PRIMARY KEY, at TEXT, logged BIGINT, day TEXT, due BIGINT NULL, created BIGINT )");
+	
+	let at = Utc.with_ymd_and_hms(2024, 2, 29, 12, 30, 0).unwrap();
+	assert_eq!(
+		Event::delete(EventFilter::And(Box::new(EventFilter::AtEqual(at)), Box::new(EventFilter::LoggedEqual(at))).into()).unwrap(),
+		(
+			"DELETE FROM Event WHERE (at = ?1 AND logged = ?2)".to_string(),
+			vec![worm::Value::Text("2024-02-29T12:30:00.000000000Z".to_string()), worm::Value::Integer(1709209800)]
+		)
+	);
+	
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Event::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let new_year = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+	let eve = Utc.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap();
+	let first = Event { id: 1, at: eve, logged: eve, day: eve.date_naive(), due: None, created: eve };
+	let second = Event { id: 2, at: new_year, logged: new_year, day: new_year.date_naive(), due: NaiveDate::from_ymd_opt(2024, 1, 15), created: new_year };
+	let autumn = Utc.with_ymd_and_hms(2024, 11, 2, 8, 0, 0).unwrap();
+	let third = Event { id: 3, at: autumn, logged: autumn, day: autumn.date_naive(), due: NaiveDate::from_ymd_opt(2023, 12, 1), created: autumn };
+	db.insert(&first).unwrap();
+	db.insert(&second).unwrap();
+	db.insert(&third).unwrap();
+	
+	assert_eq!(db.select_one(EventFilter::IdEqual(2).into()).unwrap(), Some(second));
+	
+	// The comparisons follow the instants under both storages
+	assert_eq!(db.count(EventFilter::AtLowerThan(new_year).into()).unwrap(), 1);
+	assert_eq!(db.count(EventFilter::LoggedGreaterEqualThan(new_year).into()).unwrap(), 2);
+	assert_eq!(db.count(EventFilter::DayGreaterThan(new_year.date_naive()).into()).unwrap(), 1);
+	assert_eq!(db.count(EventFilter::DueLowerThan(new_year.date_naive()).into()).unwrap(), 1);
+	assert_eq!(db.count(EventFilter::DueIsNull.into()).unwrap(), 1);
+	
+	let select = EventSelectBuilder::default().order_by(EventOrderBy::AtDesc).build();
+	let ids: Vec<u32> = db.select(select).unwrap().into_iter().map(|e| e.id).collect();
+	assert_eq!(ids, vec![3, 2, 1]);
+	
+	// Sub-second precision is kept by the text storage
+	let instant = Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap();
+	let precise = Event { id: 4, at: instant, logged: instant, day: instant.date_naive(), due: None, created: instant };
+	db.insert(&precise).unwrap();
+	let selected = db.select_one(EventFilter::IdEqual(4).into()).unwrap().unwrap();
+	assert_eq!(selected.at, precise.at);
+	assert_eq!(selected.logged, Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+}
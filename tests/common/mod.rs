@@ -0,0 +1,75 @@
+//! Connections shared by the tests, each PostgreSQL test running in a schema of its own
+#![allow(dead_code)]
+
+/// Client of the server at `WORM_POSTGRES_URL`, working in `schema` which is dropped with the
+/// client, even when the test fails
+#[cfg(feature = "postgres")]
+pub struct PgClient {
+	client: postgres::Client,
+	schema: String,
+}
+
+#[cfg(feature = "postgres")]
+impl std::ops::Deref for PgClient {
+	type Target = postgres::Client;
+	
+	fn deref(&self) -> &postgres::Client {
+		&self.client
+	}
+}
+
+#[cfg(feature = "postgres")]
+impl std::ops::DerefMut for PgClient {
+	fn deref_mut(&mut self) -> &mut postgres::Client {
+		&mut self.client
+	}
+}
+
+#[cfg(feature = "postgres")]
+impl Drop for PgClient {
+	fn drop(&mut self) {
+		let _ = self.client.batch_execute(&format!("DROP SCHEMA IF EXISTS {} CASCADE", self.schema));
+	}
+}
+
+fn postgres_url() -> String {
+	std::env::var("WORM_POSTGRES_URL").expect("WORM_POSTGRES_URL is not set, see Tests in the README")
+}
+
+/// Client working in a new `schema` of the server at `WORM_POSTGRES_URL`
+#[cfg(feature = "postgres")]
+pub fn postgres_client(schema: &str) -> PgClient {
+	let mut client = postgres::Client::connect(&postgres_url(), postgres::NoTls).unwrap();
+	client
+		.batch_execute(&format!(
+			"DROP SCHEMA IF EXISTS {0} CASCADE; CREATE SCHEMA {0}; SET search_path TO {0}",
+			schema
+		))
+		.unwrap();
+	PgClient { client, schema: schema.to_string() }
+}
+
+/// Pool of a single connection to an in-memory SQLite database, as every connection opens a new
+/// database
+#[cfg(feature = "sqlx")]
+pub async fn sqlite_pool() -> sqlx::SqlitePool {
+	sqlx::sqlite::SqlitePoolOptions::new()
+		.max_connections(1)
+		.connect("sqlite::memory:")
+		.await
+		.unwrap()
+}
+
+/// Pool working in a new `schema` of the server at `WORM_POSTGRES_URL`
+///
+/// The schema is left to the next run, which drops it.
+#[cfg(feature = "sqlx")]
+pub async fn sqlx_postgres_pool(schema: &str) -> sqlx::PgPool {
+	let options: sqlx::postgres::PgConnectOptions = postgres_url().parse().unwrap();
+	let pool = sqlx::PgPool::connect_with(options.options([("search_path", schema)])).await.unwrap();
+	sqlx::raw_sql(&format!("DROP SCHEMA IF EXISTS {0} CASCADE; CREATE SCHEMA {0}", schema))
+		.execute(&pool)
+		.await
+		.unwrap();
+	pool
+}
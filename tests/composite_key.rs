@@ -0,0 +1,53 @@
+use worm::Dialect;
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Membership {
+	#[text()]
+	role: String,
+	#[integer(primary = true)]
+	group_id: u32,
+	#[integer(primary = true)]
+	user_id: u32,
+	#[integer(null = true)]
+	since: Option<i64>,
+}
+
+#[test]
+fn statements() {
+	assert_eq!(Membership::create_table(), "CREATE TABLE IF NOT EXISTS Membership ( role TEXT, group_id INTEGER NOT NULL, user_id INTEGER NOT NULL, since INTEGER NULL, PRIMARY KEY (group_id, user_id) )");
+	assert_eq!(Membership::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Membership ( role TEXT, group_id BIGINT NOT NULL, user_id BIGINT NOT NULL, since BIGINT NULL, PRIMARY KEY (group_id, user_id) )");
+	assert_eq!(Membership::update_by_id(), "UPDATE Membership SET role = ?3, group_id = ?4, user_id = ?5, since = ?6 WHERE group_id = ?1 AND user_id = ?2");
+	assert_eq!(Membership::update_by_id_for(Dialect::Postgres), "UPDATE Membership SET role = $3, group_id = $4, user_id = $5, since = $6 WHERE group_id = $1 AND user_id = $2");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Membership::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let admin = Membership { role: String::from("admin"), group_id: 1, user_id: 7, since: None };
+	let member = Membership { role: String::from("member"), group_id: 2, user_id: 7, since: Some(5) };
+	db.insert(&admin).unwrap();
+	db.insert(&member).unwrap();
+	assert!(matches!(db.insert(&admin), Err(worm::Error::PrimaryKeyViolation(_))));
+	
+	let key = MembershipKey { group_id: 2, user_id: 7 };
+	assert_eq!(db.get_by_key(key.clone()).unwrap(), Some(member.clone()));
+	assert_eq!(db.get_by_key(MembershipKey { group_id: 7, user_id: 2 }).unwrap(), None);
+	
+	// The key of the row is bound in the WHERE clause, not its other columns
+	let promoted = Membership { role: String::from("owner"), ..admin.clone() };
+	db.update_by_id(&promoted).unwrap();
+	assert_eq!(db.get_by_key(MembershipKey::from(&admin)).unwrap(), Some(promoted.clone()));
+	assert_eq!(db.get_by_key(key.clone()).unwrap(), Some(member.clone()));
+	
+	// Updating by key may move the row to another key
+	let moved = Membership { group_id: 3, ..member.clone() };
+	db.update_by_key(&key, &moved).unwrap();
+	assert_eq!(db.get_by_key(key).unwrap(), None);
+	assert_eq!(db.get_by_key(MembershipKey::from(&moved)).unwrap(), Some(moved.clone()));
+	
+	db.delete_by_key(MembershipKey::from(&promoted)).unwrap();
+	assert_eq!(db.select_all().unwrap(), vec![moved]);
+}
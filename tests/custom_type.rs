@@ -0,0 +1,80 @@
+use worm::{Dialect, FromSqlValue, ToSqlValue, Value};
+
+/// An email address, stored lower case
+#[derive(Clone, Debug, PartialEq)]
+pub struct Email(String);
+
+impl ToSqlValue for Email {
+	fn to_sql_value(&self) -> Value {
+		Value::Text(self.0.to_lowercase())
+	}
+}
+
+impl FromSqlValue for Email {
+	fn from_sql_value(value: Value) -> worm::Result<Self> {
+		match String::from_sql_value(value)? {
+			v if v.contains('@') => Ok(Email(v)),
+			v => Err(worm::Error::Conversion(format!("{} is not an email address", v))),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Cents(i64);
+
+impl ToSqlValue for Cents {
+	fn to_sql_value(&self) -> Value {
+		self.0.to_sql_value()
+	}
+}
+
+impl FromSqlValue for Cents {
+	fn from_sql_value(value: Value) -> worm::Result<Self> {
+		i64::from_sql_value(value).map(Cents)
+	}
+}
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Customer {
+	#[integer(primary = true)]
+	id: u32,
+	#[column(type = "varchar", size = 120, unique = true)]
+	email: Email,
+	#[column(type = "integer")]
+	balance: Cents,
+	#[column(type = "integer", null = true)]
+	credit_limit: Option<Cents>,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Customer::create_table(), "CREATE TABLE IF NOT EXISTS Customer ( id INTEGER NOT NULL PRIMARY KEY, email VARCHAR(120) UNIQUE, balance INTEGER, credit_limit INTEGER NULL )");
+	assert_eq!(Customer::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Customer ( id BIGINT NOT NULL PRIMARY KEY, email VARCHAR(120) UNIQUE, balance BIGINT, credit_limit BIGINT NULL )");
+	assert_eq!(
+		Customer::count_statement(CustomerFilter::BalanceLowerThan(Cents(0)).into()).unwrap(),
+		("SELECT COUNT(*) FROM Customer WHERE balance < ?1".to_string(), vec![Value::Integer(0)])
+	);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Customer::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	db.insert(&Customer { id: 1, email: Email("Ann@Example.com".to_string()), balance: Cents(-250), credit_limit: Some(Cents(1000)) }).unwrap();
+	db.insert(&Customer { id: 2, email: Email("bob@example.com".to_string()), balance: Cents(1200), credit_limit: None }).unwrap();
+	
+	let ann = db.select_one(CustomerFilter::BalanceLowerThan(Cents(0)).into()).unwrap().unwrap();
+	assert_eq!(ann.email, Email("ann@example.com".to_string()));
+	assert_eq!(ann.credit_limit, Some(Cents(1000)));
+	assert_eq!(db.count(CustomerFilter::EmailEqual(Email("BOB@example.com".to_string())).into()).unwrap(), 1);
+	assert!(matches!(
+		db.insert(&Customer { id: 3, email: Email("ANN@example.com".to_string()), balance: Cents(0), credit_limit: None }),
+		Err(worm::Error::UniqueViolation(_))
+	));
+	
+	// The conversion of the type reports the values it rejects
+	conn.execute("UPDATE Customer SET email = 'nobody' WHERE id = 2", []).unwrap();
+	assert!(matches!(db.select_all(), Err(worm::Error::Conversion(_))));
+}
@@ -0,0 +1,76 @@
+use worm::{Column, Dialect, Value};
+
+#[derive(worm::Column, Clone, Copy, Debug, PartialEq)]
+#[column(rename_all = "snake_case")]
+pub enum Status {
+	Active,
+	OnHold,
+	#[column(name = "closed (archived)")]
+	Closed,
+}
+
+#[derive(worm::Column, Clone, Copy, Debug, PartialEq)]
+#[column(storage = "integer")]
+pub enum Priority {
+	Low = 1,
+	High = 10,
+}
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Ticket {
+	#[integer(primary = true)]
+	id: u32,
+	#[enumeration(check = true)]
+	status: Status,
+	#[enumeration(check = true, null = true)]
+	priority: Option<Priority>,
+	#[enumeration()]
+	previous: Status,
+}
+
+#[test]
+fn column() {
+	assert_eq!(Status::sql_type(Dialect::Postgres), "TEXT");
+	assert_eq!(Priority::sql_type(Dialect::Postgres), "BIGINT");
+	assert_eq!(Priority::sql_literals(), vec!["1", "10"]);
+	assert_eq!(worm::ToSqlValue::to_sql_value(&Status::OnHold), Value::Text("on_hold".to_string()));
+	assert_eq!(worm::ToSqlValue::to_sql_value(&Priority::High), Value::Integer(10));
+	assert_eq!(<Priority as worm::FromSqlValue>::from_sql_value(Value::Integer(1)).unwrap(), Priority::Low);
+	assert!(matches!(
+		<Status as worm::FromSqlValue>::from_sql_value(Value::Text("Active".to_string())),
+		Err(worm::Error::Conversion(_))
+	));
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Ticket::create_table(), "CREATE TABLE IF NOT EXISTS Ticket ( id INTEGER NOT NULL PRIMARY KEY, status TEXT CHECK (status IN ('active', 'on_hold', 'closed (archived)')), priority INTEGER NULL CHECK (priority IN (1, 10)), previous TEXT )");
+	assert_eq!(Ticket::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Ticket ( id BIGINT NOT NULL PRIMARY KEY, status TEXT CHECK (status IN ('active', 'on_hold', 'closed (archived)')), priority BIGINT NULL CHECK (priority IN (1, 10)), previous TEXT )");
+	assert_eq!(
+		Ticket::count_statement(TicketFilter::StatusEqual(Status::Closed).into()).unwrap(),
+		("SELECT COUNT(*) FROM Ticket WHERE status = ?1".to_string(), vec![Value::Text("closed (archived)".to_string())])
+	);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Ticket::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let ticket = Ticket { id: 1, status: Status::OnHold, priority: Some(Priority::High), previous: Status::Active };
+	db.insert(&ticket).unwrap();
+	db.insert(&Ticket { id: 2, status: Status::Closed, priority: None, previous: Status::OnHold }).unwrap();
+	
+	assert_eq!(db.select_one(TicketFilter::StatusEqual(Status::OnHold).into()).unwrap(), Some(ticket));
+	assert_eq!(db.count(TicketFilter::PriorityEqual(Priority::High).into()).unwrap(), 1);
+	assert_eq!(db.count(TicketFilter::PriorityIsNull.into()).unwrap(), 1);
+	
+	// The CHECK constraint rejects values outside of the variants
+	assert!(conn.execute("INSERT INTO Ticket VALUES (3, 'deleted', NULL, 'active')", []).is_err());
+	assert!(conn.execute("INSERT INTO Ticket VALUES (3, 'active', 5, 'active')", []).is_err());
+	
+	// Without constraint, an unknown value fails on select
+	conn.execute("INSERT INTO Ticket VALUES (3, 'active', NULL, 'deleted')", []).unwrap();
+	assert!(matches!(db.select_all(), Err(worm::Error::Conversion(_))));
+}
@@ -0,0 +1,54 @@
+use worm::{Error, Table};
+
+#[derive(Table)]
+pub struct Account {
+	#[integer(primary = true)]
+	id: u32,
+	#[varchar(size = 60, unique = true)]
+	email: String,
+}
+
+#[test]
+fn main() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let accounts = Account::from_rusqlite(&conn);
+	accounts.create_table().unwrap();
+	
+	accounts
+		.insert(&Account {
+			id: 1,
+			email: "jane@example.com".to_string(),
+		})
+		.unwrap();
+	
+	let e = accounts
+		.insert(&Account {
+			id: 1,
+			email: "john@example.com".to_string(),
+		})
+		.unwrap_err();
+	assert!(matches!(e, Error::PrimaryKeyViolation(_)), "{:?}", e);
+	
+	let e = accounts
+		.insert(&Account {
+			id: 2,
+			email: "jane@example.com".to_string(),
+		})
+		.unwrap_err();
+	assert!(matches!(e, Error::UniqueViolation(_)), "{:?}", e);
+	
+	conn.execute("CREATE TABLE Note ( text TEXT NOT NULL )", ())
+		.unwrap();
+	let e = conn
+		.execute("INSERT INTO Note (text) VALUES (NULL)", ())
+		.unwrap_err();
+	assert!(matches!(Error::from(e), Error::NotNullViolation(_)));
+	
+	let e = conn
+		.query_row("SELECT text FROM Note", (), |r| r.get::<_, String>(0))
+		.unwrap_err();
+	assert!(matches!(Error::from(e), Error::NotFound));
+	
+	let e = accounts.delete_table().and_then(|_| accounts.count_all()).unwrap_err();
+	assert!(matches!(e, Error::Driver(_)), "{:?}", e);
+}
@@ -41,8 +41,8 @@ fn main() {
 	person_table.insert(&jim).unwrap();
 	
 	// Check Jim's age
-	let select = SelectBuilder::default()
-		.set_filter(Filter::NameEqual("Jim".to_string()))
+	let select = PersonSelectBuilder::default()
+		.set_filter(PersonFilter::NameEqual("Jim".to_string()))
 		.build();
 	let p: Person = person_table
 		.select(select)
@@ -52,14 +52,14 @@ fn main() {
 		.unwrap();
 	assert_eq!(p.age, 27);
 	// or
-	let filter = Filter::And(
-		Box::new(Filter::NameEqual("Jim".to_string())),
-		Box::new(Filter::AgeEqual(27)),
+	let filter = PersonFilter::And(
+		Box::new(PersonFilter::NameEqual("Jim".to_string())),
+		Box::new(PersonFilter::AgeEqual(27)),
 	);
 	assert_eq!(person_table.count(filter.into()).unwrap(), 1);
 	
 	// Update Jim
-	let filter = Filter::NameEqual("Jim".to_string());
+	let filter = PersonFilter::NameEqual("Jim".to_string());
 	let jim: Person = person_table.select_one(filter.into()).unwrap().unwrap();
 	let update_to = Person {
 		id: 2,
@@ -68,7 +68,7 @@ fn main() {
 	};
 	person_table.update_to(&jim, &update_to).unwrap();
 	
-	let filter = Filter::NameEqual("Jim".to_string());
+	let filter = PersonFilter::NameEqual("Jim".to_string());
 	let updated_jane: Person = person_table
 		.select(filter.into())
 		.unwrap()
@@ -79,7 +79,7 @@ fn main() {
 	
 	// Check Jim's age
 	let p: Person = person_table
-		.select(Filter::AgeGreaterThan(27).into())
+		.select(PersonFilter::AgeGreaterThan(27).into())
 		.unwrap()
 		.into_iter()
 		.nth(0)
@@ -87,9 +87,9 @@ fn main() {
 	assert_eq!(p.age, 28);
 	
 	// Delete John
-	let filter = Filter::And(
-		Box::new(Filter::NameEqual("John".to_string())),
-		Box::new(Filter::AgeEqual(24)),
+	let filter = PersonFilter::And(
+		Box::new(PersonFilter::NameEqual("John".to_string())),
+		Box::new(PersonFilter::AgeEqual(24)),
 	);
 	let john: Person = person_table
 		.select(filter.into())
@@ -99,7 +99,7 @@ fn main() {
 		.unwrap();
 	person_table.delete(john.into()).unwrap();
 	
-	let filter = Filter::NameEqual("John".to_string());
+	let filter = PersonFilter::NameEqual("John".to_string());
 	let john: Vec<Person> = person_table.select(filter.into()).unwrap();
 	assert_eq!(john.len(), 0);
 	
@@ -0,0 +1,49 @@
+use worm::Dialect;
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Reading {
+	#[integer(primary = true)]
+	id: i64,
+	sensor: String,
+	value: f64,
+	calibrated: bool,
+	payload: Vec<u8>,
+	note: Option<String>,
+	#[varchar(size = 10, unique = true)]
+	code: String,
+}
+
+#[test]
+fn main() {
+	assert_eq!(Reading::create_table(), "CREATE TABLE IF NOT EXISTS Reading ( id INTEGER NOT NULL PRIMARY KEY, sensor TEXT, value REAL, calibrated BOOLEAN, payload BLOB, note TEXT NULL, code VARCHAR(10) UNIQUE )");
+	assert_eq!(Reading::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Reading ( id BIGINT NOT NULL PRIMARY KEY, sensor TEXT, value DOUBLE PRECISION, calibrated BOOLEAN, payload BYTEA, note TEXT NULL, code VARCHAR(10) UNIQUE )");
+	
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Reading::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let reading = Reading {
+		id: 1,
+		sensor: String::from("north"),
+		value: 21.5,
+		calibrated: true,
+		payload: vec![0, 159, 146, 150],
+		note: None,
+		code: String::from("N-1"),
+	};
+	db.insert(&reading).unwrap();
+	db.insert(&Reading {
+		id: 2,
+		sensor: String::from("south"),
+		value: 19.25,
+		calibrated: false,
+		payload: vec![],
+		note: Some(String::from("drifting")),
+		code: String::from("N-2"),
+	})
+	.unwrap();
+	
+	assert_eq!(db.select_one(ReadingFilter::IdEqual(1).into()).unwrap(), Some(reading));
+	assert_eq!(db.count(ReadingFilter::ValueGreaterThan(20.0).into()).unwrap(), 1);
+	assert_eq!(db.count(ReadingFilter::CalibratedEqual(false).into()).unwrap(), 1);
+}
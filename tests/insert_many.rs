@@ -0,0 +1,82 @@
+mod common;
+
+use worm::Dialect;
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Sample {
+	#[integer(primary = true)]
+	id: u32,
+	#[real()]
+	value: f64,
+}
+
+#[derive(worm::Table, Clone)]
+pub struct Line {
+	#[integer(primary = true, auto = true)]
+	id: i64,
+	#[text()]
+	text: String,
+}
+
+#[test]
+fn main() {
+	let rows: Vec<Sample> = (0..1200).map(|id| Sample { id, value: id as f64 / 2.0 }).collect();
+	assert_eq!(Sample::insert_rows_for(Dialect::Sqlite, 2), "INSERT INTO Sample (id, value) VALUES (?1, ?2), (?3, ?4)");
+	assert_eq!(Line::insert_rows_for(Dialect::Postgres, 3), "INSERT INTO Line (text) VALUES ($1), ($2), ($3)");
+	
+	// 999 parameters fit 499 rows of two columns
+	let statements = Sample::insert_many(&rows).unwrap();
+	assert_eq!(statements.iter().map(|(_, params)| params.len()).collect::<Vec<usize>>(), vec![998, 998, 404]);
+	assert_eq!(statements[2].0, Sample::insert_rows_for(Dialect::Sqlite, 202));
+	assert!(Sample::insert_many(&[]).unwrap().is_empty());
+	
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Sample::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	assert_eq!(db.insert_many(&rows).unwrap(), 1200);
+	assert_eq!(db.count_all().unwrap(), 1200);
+	assert_eq!(db.get_by_key(SampleKey { id: 1001 }).unwrap(), Some(Sample { id: 1001, value: 500.5 }));
+	
+	// The rows are inserted in a transaction, a failure inserts none of them
+	let extra = [Sample { id: 5000, value: 0.0 }, Sample { id: 3, value: 0.0 }];
+	assert!(matches!(db.insert_many(&extra), Err(worm::Error::PrimaryKeyViolation(_))));
+	assert_eq!(db.count_all().unwrap(), 1200);
+	
+	// The transaction of the caller is joined
+	conn.execute_batch("BEGIN").unwrap();
+	assert_eq!(db.insert_iter(extra.iter().take(1)).unwrap(), 1);
+	conn.execute_batch("ROLLBACK").unwrap();
+	assert_eq!(db.count_all().unwrap(), 1200);
+	
+	let lines = Line::from_rusqlite(&conn);
+	lines.create_table().unwrap();
+	let rows = [Line { id: 0, text: "a".to_string() }, Line { id: 0, text: "b".to_string() }];
+	assert_eq!(lines.insert_iter(rows.iter()).unwrap(), 2);
+	assert_eq!(lines.get_by_key(LineKey { id: 2 }).unwrap().map(|l| l.text), Some(String::from("b")));
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn postgres() {
+	let mut client = common::postgres_client("worm_insert_many");
+	let db = PgSample::from_postgres(&mut client);
+	db.create_table().unwrap();
+	
+	let rows: Vec<PgSample> = (0..40000).map(|id| PgSample { id, value: id as f64 }).collect();
+	assert_eq!(db.insert_many(&rows).unwrap(), 40000);
+	assert_eq!(db.count_all().unwrap(), 40000);
+	assert!(matches!(db.insert_many(&rows[..2]), Err(worm::Error::PrimaryKeyViolation(_))));
+	assert_eq!(db.count_all().unwrap(), 40000);
+	
+	db.delete_table().unwrap();
+}
+
+#[cfg(feature = "postgres")]
+#[derive(worm::Table)]
+pub struct PgSample {
+	#[integer(primary = true)]
+	id: u32,
+	value: f64,
+}
@@ -0,0 +1,57 @@
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Status {
+	#[integer(primary = true)]
+	id: u8,
+	#[integer()]
+	code: i8,
+	shift: i16,
+	port: u16,
+	position: usize,
+	delta: isize,
+	counter: u64,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Status::create_table(), "CREATE TABLE IF NOT EXISTS Status ( id INTEGER NOT NULL PRIMARY KEY, code INTEGER, shift INTEGER, port INTEGER, position INTEGER, delta INTEGER, counter INTEGER )");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Status::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let extremes = Status {
+		id: u8::MAX,
+		code: i8::MIN,
+		shift: i16::MIN,
+		port: u16::MAX,
+		position: i64::MAX as usize,
+		delta: isize::MIN,
+		counter: i64::MAX as u64,
+	};
+	db.insert(&extremes).unwrap();
+	assert_eq!(db.select_one(StatusFilter::PortEqual(u16::MAX).into()).unwrap(), Some(extremes));
+	assert_eq!(db.count(StatusFilter::ShiftLowerThan(0).into()).unwrap(), 1);
+}
+
+#[test]
+fn overflow() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Status::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let status = Status { id: 1, code: 0, shift: 0, port: 0, position: 0, delta: 0, counter: u64::MAX };
+	match db.insert(&status) {
+		Err(worm::Error::Conversion(message)) => {
+			assert_eq!(message, "18446744073709551615 overflows a signed 64-bit INTEGER column (max 9223372036854775807)")
+		}
+		r => panic!("Expected a conversion error, got {:?}", r),
+	}
+	assert_eq!(db.count(StatusFilter::CounterGreaterThan(u64::MAX).into()).ok(), None);
+	
+	// A value read from the database that doesn't fit in the field is reported as well
+	conn.execute("INSERT INTO Status VALUES (1, 300, 0, 0, 0, 0, 0)", []).unwrap();
+	assert!(matches!(db.select_all(), Err(worm::Error::Conversion(_))));
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "json")]
+
+mod common;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use worm::{JsonStorage, JsonValue};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Settings {
+	theme: String,
+	volume: i64,
+	notifications: Notifications,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Notifications {
+	email: bool,
+}
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Profile {
+	#[integer(primary = true)]
+	id: u32,
+	#[json()]
+	settings: Settings,
+	#[json()]
+	tags: Vec<String>,
+	#[json(null = true)]
+	previous: Option<Settings>,
+}
+
+/// Map with non-string keys, which serde_json can't serialize
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Grid {
+	#[integer(primary = true)]
+	id: u32,
+	#[json()]
+	cells: BTreeMap<(u8, u8), String>,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Profile::create_table(), "CREATE TABLE IF NOT EXISTS Profile ( id INTEGER NOT NULL PRIMARY KEY, settings TEXT, tags TEXT, previous TEXT NULL )");
+	assert_eq!(Profile::create_table_for(worm::Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Profile ( id BIGINT NOT NULL PRIMARY KEY, settings JSONB, tags JSONB, previous JSONB NULL )");
+	
+	let filter = ProfileFilter::SettingsPathEqual("$.notifications.email".to_string(), JsonValue::Bool(true));
+	assert_eq!(
+		Profile::count_statement(filter.clone().into()).unwrap(),
+		(
+			"SELECT COUNT(*) FROM Profile WHERE json_extract(settings, ?1) = ?2".to_string(),
+			vec![worm::Value::Text("$.notifications.email".to_string()), worm::Value::Integer(1)]
+		)
+	);
+	assert_eq!(
+		Profile::count_statement_for(worm::Dialect::Postgres, filter.into()).unwrap(),
+		(
+			"SELECT COUNT(*) FROM Profile WHERE jsonb_path_query_first(settings, CAST($1 AS TEXT)::jsonpath) = $2".to_string(),
+			vec![worm::Value::Text("$.notifications.email".to_string()), worm::Value::Json("true".to_string())]
+		)
+	);
+}
+
+#[test]
+fn unserializable() {
+	let cells = BTreeMap::from([((0, 0), "start".to_string())]);
+	assert!(matches!(cells.to_json_value(), Err(worm::Error::Conversion(_))));
+	assert!(matches!(Grid::count_statement(GridFilter::CellsEqual(cells.clone()).into()), Err(worm::Error::Conversion(_))));
+	
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Grid::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	let grid = Grid { id: 1, cells };
+	assert!(matches!(db.insert(&grid), Err(worm::Error::Conversion(_))));
+	assert!(matches!(db.insert_many(&[grid]), Err(worm::Error::Conversion(_))));
+	assert_eq!(db.count_all().unwrap(), 0);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	test(&Profile::from_rusqlite(&conn)).unwrap();
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn postgres() {
+	let mut client = common::postgres_client("worm_json_postgres");
+	test(&Profile::from_postgres(&mut client)).unwrap();
+}
+
+#[cfg(feature = "sqlx")]
+#[tokio::test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+async fn sqlx_postgres() {
+	let pool = common::sqlx_postgres_pool("worm_json_sqlx_postgres").await;
+	let db = SqlxProfile::from_sqlx_postgres(&pool);
+	db.create_table().await.unwrap();
+	
+	let profile = SqlxProfile {
+		id: 1,
+		settings: Settings { theme: String::from("dark"), volume: 7, notifications: Notifications { email: true } },
+		previous: None,
+	};
+	db.insert(&profile).await.unwrap();
+	assert_eq!(db.select_all().await.unwrap(), vec![profile]);
+	let filter = SqlxProfileFilter::SettingsPathEqual("$.volume".to_string(), 7.into());
+	assert_eq!(db.count(filter.into()).await.unwrap(), 1);
+	db.delete_table().await.unwrap();
+}
+
+#[cfg(feature = "sqlx")]
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct SqlxProfile {
+	#[integer(primary = true)]
+	id: u32,
+	#[json()]
+	settings: Settings,
+	#[json(null = true)]
+	previous: Option<Settings>,
+}
+
+fn test(db: &ProfileTable<'_>) -> Result<(), Box<dyn Error>> {
+	db.create_table()?;
+	db.delete_all()?;
+	
+	let dark = Profile {
+		id: 1,
+		settings: Settings { theme: String::from("dark"), volume: 7, notifications: Notifications { email: true } },
+		tags: vec!["admin".to_string()],
+		previous: None,
+	};
+	let light = Profile {
+		id: 2,
+		settings: Settings { theme: String::from("light"), volume: 3, notifications: Notifications { email: false } },
+		tags: vec![],
+		previous: Some(Settings { theme: String::from("dark"), volume: 0, notifications: Notifications { email: true } }),
+	};
+	db.insert(&dark)?;
+	db.insert(&light)?;
+	
+	assert_eq!(db.select_one(ProfileFilter::IdEqual(1).into())?.as_ref(), Some(&dark));
+	assert_eq!(db.select_one(ProfileFilter::IdEqual(2).into())?.as_ref(), Some(&light));
+	assert_eq!(db.count(ProfileFilter::PreviousIsNull.into())?, 1);
+	assert_eq!(db.count(ProfileFilter::TagsEqual(vec![]).into())?, 1);
+	
+	let path = |path: &str, value: JsonValue| ProfileFilter::SettingsPathEqual(path.to_string(), value);
+	assert_eq!(db.count(path("$.theme", "dark".into()).into())?, 1);
+	assert_eq!(db.count(path("$.volume", 3.into()).into())?, 1);
+	assert_eq!(db.count(path("$.notifications.email", true.into()).into())?, 1);
+	assert_eq!(db.count(path("$.notifications.email", false.into()).into())?, 1);
+	assert_eq!(db.count(ProfileFilter::PreviousPathEqual("$.theme".to_string(), "dark".into()).into())?, 1);
+	
+	Ok(())
+}
@@ -0,0 +1,72 @@
+use worm::Table;
+
+#[derive(Table)]
+pub struct Author {
+	#[integer(primary = true)]
+	id: u32,
+	#[text()]
+	name: String,
+}
+
+#[derive(Table)]
+#[table(prefix = "Entry")]
+pub struct BlogEntry {
+	#[integer(primary = true)]
+	id: u32,
+	#[integer()]
+	author_id: u32,
+	#[text()]
+	title: String,
+}
+
+#[derive(Table)]
+#[table(module = "tag")]
+pub struct Tag {
+	#[integer(primary = true)]
+	id: u32,
+	#[text()]
+	label: String,
+}
+
+#[test]
+fn main() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	
+	let authors: AuthorTable = Author::from_rusqlite(&conn);
+	authors.create_table().unwrap();
+	authors
+		.insert(&Author {
+			id: 1,
+			name: "Jane".to_string(),
+		})
+		.unwrap();
+	
+	let entries: EntryTable = BlogEntry::from_rusqlite(&conn);
+	entries.create_table().unwrap();
+	entries
+		.insert(&BlogEntry {
+			id: 1,
+			author_id: 1,
+			title: "Hello".to_string(),
+		})
+		.unwrap();
+	
+	let tags: tag::Table = Tag::from_rusqlite(&conn);
+	tags.create_table().unwrap();
+	tags.insert(&Tag {
+		id: 1,
+		label: "rust".to_string(),
+	})
+	.unwrap();
+	
+	assert_eq!(authors.count(AuthorFilter::NameEqual("Jane".to_string()).into()).unwrap(), 1);
+	let select = EntrySelectBuilder::default()
+		.set_filter(EntryFilter::AuthorIdEqual(1))
+		.build();
+	assert_eq!(entries.select(select).unwrap()[0].title, "Hello");
+	let tag = tags
+		.select_one(tag::Filter::LabelEqual("rust".to_string()).into())
+		.unwrap()
+		.unwrap();
+	assert_eq!(tag.id, 1);
+}
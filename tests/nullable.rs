@@ -0,0 +1,78 @@
+use worm::Table;
+
+#[derive(Table, Clone, Debug, PartialEq)]
+pub struct Contact {
+	#[integer(primary = true)]
+	id: u32,
+	#[text()]
+	name: String,
+	#[varchar(size = 60)]
+	email: Option<String>,
+	#[integer()]
+	age: Option<u32>,
+}
+
+#[derive(Table, Clone, Debug, PartialEq)]
+pub struct Note {
+	#[text()]
+	title: Option<String>,
+	#[text()]
+	body: String,
+}
+
+#[test]
+fn create_table() {
+	assert_eq!(
+		Contact::create_table(),
+		"CREATE TABLE IF NOT EXISTS Contact ( id INTEGER NOT NULL PRIMARY KEY, name TEXT, email VARCHAR(60) NULL, age INTEGER NULL )"
+	);
+}
+
+#[test]
+fn round_trip() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let contacts = Contact::from_rusqlite(&conn);
+	contacts.create_table().unwrap();
+	
+	let jane = Contact {
+		id: 1,
+		name: "Jane".to_string(),
+		email: Some("jane@example.com".to_string()),
+		age: Some(31),
+	};
+	let john = Contact {
+		id: 2,
+		name: "John".to_string(),
+		email: None,
+		age: None,
+	};
+	contacts.insert(&jane).unwrap();
+	contacts.insert(&john).unwrap();
+	
+	assert_eq!(contacts.select_all().unwrap(), vec![jane.clone(), john.clone()]);
+	
+	let found = contacts.select(ContactFilter::EmailIsNull.into()).unwrap();
+	assert_eq!(found, vec![john.clone()]);
+	let found = contacts.select(ContactFilter::AgeIsNotNull.into()).unwrap();
+	assert_eq!(found, vec![jane.clone()]);
+	let found = contacts.select(ContactFilter::AgeGreaterThan(30).into()).unwrap();
+	assert_eq!(found, vec![jane.clone()]);
+	
+	// A None field of the struct filters on IS NULL
+	contacts.delete(john.into()).unwrap();
+	assert_eq!(contacts.select_all().unwrap(), vec![jane]);
+}
+
+#[test]
+fn count_null_first_column() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let notes = Note::from_rusqlite(&conn);
+	notes.create_table().unwrap();
+	notes.insert(&Note { title: Some("Groceries".to_string()), body: "Milk".to_string() }).unwrap();
+	notes.insert(&Note { title: None, body: "Call back".to_string() }).unwrap();
+	
+	// The rows are counted whatever the value of their first column
+	assert_eq!(Note::count_all_statement().0, "SELECT COUNT(*) FROM Note");
+	assert_eq!(notes.count_all().unwrap(), 2);
+	assert_eq!(notes.count(NoteFilter::TitleIsNull.into()).unwrap(), 1);
+}
@@ -0,0 +1,57 @@
+#[derive(worm::Table)]
+pub struct Players {
+	#[integer(primary = true)]
+	id: u32,
+	#[varchar(size = 30)]
+	team: String,
+	#[integer()]
+	score: i64,
+}
+
+#[test]
+fn statement() {
+	let select = PlayersSelectBuilder::default()
+		.set_filter(PlayersFilter::ScoreGreaterThan(0))
+		.order_by(PlayersOrderBy::TeamAsc)
+		.then_by(PlayersOrderBy::ScoreDesc)
+		.set_limit(2)
+		.set_offset(1)
+		.build();
+	assert_eq!(
+		Players::select(select).unwrap().0,
+		"SELECT id, team, score FROM Players WHERE score > ?1 ORDER BY team ASC, score DESC LIMIT 2 OFFSET 1"
+	);
+	
+	// order_by replaces the previous sort keys
+	let select = PlayersSelectBuilder::default()
+		.order_by(PlayersOrderBy::TeamAsc)
+		.order_by(PlayersOrderBy::IdDesc)
+		.build();
+	assert_eq!(Players::select(select).unwrap().0, "SELECT id, team, score FROM Players ORDER BY id DESC");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Players::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	for (id, team, score) in [(1, "red", 10), (2, "blue", 30), (3, "red", 20), (4, "blue", 5), (5, "red", 15)] {
+		db.insert(&Players { id, team: String::from(team), score }).unwrap();
+	}
+	
+	let ids = |select| db.select(select).unwrap().into_iter().map(|p| p.id).collect::<Vec<u32>>();
+	
+	let select = PlayersSelectBuilder::default()
+		.order_by(PlayersOrderBy::TeamAsc)
+		.then_by(PlayersOrderBy::ScoreDesc)
+		.build();
+	assert_eq!(ids(select), vec![2, 4, 3, 5, 1]);
+	
+	let select = PlayersSelectBuilder::default()
+		.set_filter(PlayersFilter::TeamEqual(String::from("red")))
+		.order_by(PlayersOrderBy::ScoreAsc)
+		.set_limit(2)
+		.set_offset(1)
+		.build();
+	assert_eq!(ids(select), vec![5, 3]);
+}
@@ -0,0 +1,91 @@
+use worm::Dialect;
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Articles {
+	#[integer(primary = true)]
+	id: u32,
+	#[varchar(size = 60)]
+	title: String,
+	#[integer()]
+	views: i64,
+}
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Drafts {
+	#[varchar(size = 60)]
+	title: Option<String>,
+	#[integer()]
+	words: i64,
+}
+
+#[test]
+fn statement() {
+	let offset_only = || ArticlesSelectBuilder::default().set_offset(20).build();
+	assert_eq!(Articles::select_for(Dialect::Sqlite, offset_only()).unwrap().0, "SELECT id, title, views FROM Articles LIMIT -1 OFFSET 20");
+	assert_eq!(Articles::select_for(Dialect::Postgres, offset_only()).unwrap().0, "SELECT id, title, views FROM Articles OFFSET 20");
+	
+	let page = || {
+		ArticlesSelectBuilder::default()
+			.order_by(ArticlesOrderBy::IdAsc)
+			.set_limit(10)
+			.set_offset(20)
+			.build()
+	};
+	assert_eq!(Articles::select_for(Dialect::Sqlite, page()).unwrap().0, "SELECT id, title, views FROM Articles ORDER BY id ASC LIMIT 10 OFFSET 20");
+	assert_eq!(Articles::select_for(Dialect::Postgres, page()).unwrap().0, "SELECT id, title, views FROM Articles ORDER BY id ASC LIMIT 10 OFFSET 20");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Articles::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	for id in 1..=7 {
+		db.insert(&Articles { id, title: format!("Article {}", id), views: (id as i64) * 10 }).unwrap();
+	}
+	
+	let select = ArticlesSelectBuilder::default()
+		.order_by(ArticlesOrderBy::IdAsc)
+		.set_offset(5)
+		.build();
+	let ids: Vec<u32> = db.select(select).unwrap().into_iter().map(|a| a.id).collect();
+	assert_eq!(ids, vec![6, 7]);
+	
+	let select = || {
+		ArticlesSelectBuilder::default()
+			.set_filter(ArticlesFilter::ViewsGreaterThan(10))
+			.order_by(ArticlesOrderBy::ViewsDesc)
+			.build()
+	};
+	let page = db.select_page(select(), 0, 4).unwrap();
+	assert_eq!(page.total, 6);
+	assert_eq!(page.page_count(), 2);
+	assert_eq!(page.rows.iter().map(|a| a.id).collect::<Vec<u32>>(), vec![7, 6, 5, 4]);
+	
+	let page = db.select_page(select(), 1, 4).unwrap();
+	assert_eq!(page.total, 6);
+	assert_eq!(page.rows.iter().map(|a| a.id).collect::<Vec<u32>>(), vec![3, 2]);
+	
+	let page = db.select_page(select(), 2, 4).unwrap();
+	assert!(page.rows.is_empty());
+	
+	// An offset beyond usize fails instead of overflowing
+	assert!(matches!(db.select_page(select(), usize::MAX, 4), Err(worm::Error::Conversion(_))));
+	assert!(matches!(db.select_page(select(), 2, usize::MAX), Err(worm::Error::Conversion(_))));
+}
+
+#[test]
+fn null_first_column() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Drafts::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	db.insert(&Drafts { title: Some("Outline".to_string()), words: 120 }).unwrap();
+	db.insert(&Drafts { title: None, words: 40 }).unwrap();
+	
+	// The total counts the rows without a title too
+	let select = DraftsSelectBuilder::default().order_by(DraftsOrderBy::WordsDesc).build();
+	let page = db.select_page(select, 0, 5).unwrap();
+	assert_eq!(page.total, 2);
+	assert_eq!(page.page_count(), 1);
+	assert_eq!(page.rows.len(), 2);
+}
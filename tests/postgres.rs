@@ -0,0 +1,122 @@
+#![cfg(feature = "postgres")]
+//! Run against a local server with `WORM_POSTGRES_URL="postgres://postgres@localhost" cargo test --features postgres`
+
+mod common;
+
+use std::error::Error;
+
+#[derive(worm::Table, Clone)]
+pub struct PgUsers {
+	#[integer(primary = true)]
+	user_id: u32,
+	#[varchar(size = 120, unique = true, null = false)]
+	user_name: String,
+	#[varchar(size = 30)]
+	last_name: String,
+	#[integer()]
+	birthday: Option<i64>,
+}
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct PgReadings {
+	#[integer(primary = true)]
+	id: i64,
+	value: f64,
+	calibrated: bool,
+	payload: Vec<u8>,
+	level: u8,
+	counter: u64,
+}
+
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn main() {
+	let mut client = common::postgres_client("worm_postgres_main");
+	test(&mut client).unwrap();
+}
+
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn types() {
+	let mut client = common::postgres_client("worm_postgres_types");
+	test_types(&mut client).unwrap();
+}
+
+fn test(client: &mut postgres::Client) -> Result<(), Box<dyn Error>> {
+	let db = PgUsers::from_postgres(client);
+	db.create_table()?;
+	db.delete_all()?;
+	
+	let john = PgUsers {
+		user_id: 4,
+		user_name: String::from("John"),
+		last_name: String::from("O'Brien"),
+		birthday: Some(214014012),
+	};
+	db.insert(&john)?;
+	db.insert(&PgUsers {
+		user_id: 5,
+		user_name: String::from("Jane"),
+		last_name: String::from("Bin"),
+		birthday: None,
+	})?;
+	
+	assert!(matches!(db.insert(&john), Err(worm::Error::PrimaryKeyViolation(_))));
+	assert!(matches!(
+		db.insert(&PgUsers { user_id: 6, ..john.clone() }),
+		Err(worm::Error::UniqueViolation(_))
+	));
+	
+	assert_eq!(db.count_all()?, 2);
+	assert_eq!(db.count(PgUsersFilter::LastNameEqual(String::from("O'Brien")).into())?, 1);
+	assert_eq!(db.count(PgUsersFilter::BirthdayIsNull.into())?, 1);
+	
+	let jane = db
+		.select_one(PgUsersFilter::UserIdGreaterThan(4).into())?
+		.ok_or("Unable to find Jane")?;
+	assert_eq!(jane.user_name, "Jane");
+	assert_eq!(jane.birthday, None);
+	
+	let update = PgUsers { birthday: Some(123), ..john.clone() };
+	db.update_to(&john, &update)?;
+	let john = db
+		.select(PgUsersFilter::UserNameEqual(String::from("John")).into())?
+		.into_iter()
+		.next()
+		.ok_or("Unable to find John")?;
+	assert_eq!(john.birthday, Some(123));
+	
+	let select = PgUsersSelectBuilder::default()
+		.order_by(PgUsersOrderBy::UserIdDesc)
+		.set_offset(1)
+		.build();
+	assert_eq!(db.select(select)?.into_iter().map(|u| u.user_id).collect::<Vec<u32>>(), vec![4]);
+	let page = db.select_page(PgUsersSelectBuilder::default().order_by(PgUsersOrderBy::UserIdAsc).build(), 1, 1)?;
+	assert_eq!((page.total, page.rows[0].user_id), (2, 5));
+	
+	db.delete(PgUsersFilter::UserIdEqual(4).into())?;
+	assert_eq!(db.count_all()?, 1);
+	
+	db.delete_table()?;
+	Ok(())
+}
+
+fn test_types(client: &mut postgres::Client) -> Result<(), Box<dyn Error>> {
+	let db = PgReadings::from_postgres(client);
+	db.create_table()?;
+	db.delete_all()?;
+	
+	let reading = PgReadings { id: 1, value: 21.5, calibrated: true, payload: vec![0, 159, 146, 150], level: u8::MAX, counter: i64::MAX as u64 };
+	db.insert(&reading)?;
+	assert_eq!(db.select_one(PgReadingsFilter::ValueGreaterThan(20.0).into())?, Some(reading));
+	assert_eq!(db.count(PgReadingsFilter::CalibratedIsTrue.into())?, 1);
+	assert_eq!(db.count(PgReadingsFilter::PayloadEqual(vec![0, 159, 146, 150]).into())?, 1);
+	assert_eq!(db.count(PgReadingsFilter::LevelEqual(u8::MAX).into())?, 1);
+	assert!(matches!(
+		db.count(PgReadingsFilter::CounterGreaterThan(u64::MAX).into()),
+		Err(worm::Error::Conversion(_))
+	));
+	
+	db.delete_table()?;
+	Ok(())
+}
@@ -0,0 +1,60 @@
+// Field names as found in an existing camelCase schema
+#![allow(non_snake_case)]
+
+use worm::Dialect;
+
+#[derive(worm::Table, Debug, PartialEq)]
+#[table(name = "tbl_users", schema = "main", rename_all = "snake_case")]
+pub struct User {
+	#[integer(primary = true, column = "usr_id")]
+	id: u32,
+	#[varchar(size = 30, column = "usr_first_name")]
+	first_name: String,
+	#[integer()]
+	loginCount: i64,
+}
+
+#[derive(worm::Table)]
+#[table(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct Setting {
+	#[varchar(size = 30, primary = true)]
+	setting_key: String,
+	#[text()]
+	value: String,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(User::create_table(), "CREATE TABLE IF NOT EXISTS main.tbl_users ( usr_id INTEGER NOT NULL PRIMARY KEY, usr_first_name VARCHAR(30), login_count INTEGER )");
+	assert_eq!(User::insert_for(Dialect::Postgres), "INSERT INTO main.tbl_users (usr_id, usr_first_name, login_count) VALUES ($1, $2, $3)");
+	assert_eq!(User::update_by_id(), "UPDATE main.tbl_users SET usr_id = ?2, usr_first_name = ?3, login_count = ?4 WHERE usr_id = ?1");
+	
+	let select = UserSelectBuilder::default()
+		.set_filter(UserFilter::FirstNameEqual(String::from("Ann")))
+		.order_by(UserOrderBy::LoginCountDesc)
+		.build();
+	assert_eq!(
+		User::select(select).unwrap().0,
+		"SELECT usr_id, usr_first_name, login_count FROM main.tbl_users WHERE usr_first_name = ?1 ORDER BY login_count DESC"
+	);
+	assert_eq!(User::count_statement(UserFilter::IdEqual(1).into()).unwrap().0, "SELECT COUNT(*) FROM main.tbl_users WHERE usr_id = ?1");
+	
+	assert_eq!(Setting::create_table(), "CREATE TABLE IF NOT EXISTS Setting ( SETTING_KEY VARCHAR(30) NOT NULL PRIMARY KEY, VALUE TEXT )");
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = User::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	let ann = User { id: 1, first_name: String::from("Ann"), loginCount: 3 };
+	db.insert(&ann).unwrap();
+	
+	assert_eq!(db.select_one(UserFilter::LoginCountGreaterThan(2).into()).unwrap(), Some(ann));
+	db.delete(UserFilter::FirstNameEqual(String::from("Ann")).into()).unwrap();
+	assert_eq!(db.count_all().unwrap(), 0);
+	
+	// The columns are those of an existing schema
+	conn.execute("INSERT INTO tbl_users (usr_id, usr_first_name, login_count) VALUES (2, 'Bob', 0)", ()).unwrap();
+	assert_eq!(db.select_all().unwrap()[0].first_name, "Bob");
+}
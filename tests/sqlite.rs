@@ -57,6 +57,19 @@ fn test(conn: &rusqlite::Connection) -> Result<(), Box<dyn Error>> {
 		.ok_or("Unable to find John Doe's contact")?;
 	assert_eq!(contact.birthday, birthday);
 	
+	// Values containing quotes are bound, not interpolated into the statement
+	assert_eq!(db.count(UsersFilter::LastNameEqual(String::from("Bin' OR '1' = '1")).into())?, 0);
+	db.insert(&Users {
+		user_id: 6,
+		user_name: String::from("O'Brien"),
+		first_name: String::from("Pat"),
+		last_name: String::from("O'Brien"),
+		note: String::from("Quote ' in the note"),
+		birthday: 214014016,
+	})?;
+	assert_eq!(db.count(UsersFilter::LastNameEqual(String::from("O'Brien")).into())?, 1);
+	db.delete(UsersFilter::UserNameEqual(String::from("O'Brien")).into())?;
+	
 	let birthday = 123;
 	// Update contact
 	let update = Users {
@@ -0,0 +1,94 @@
+#![cfg(feature = "sqlx")]
+
+mod common;
+
+use std::error::Error;
+
+#[derive(worm::Table, Clone)]
+pub struct AsyncUsers {
+	#[integer(primary = true)]
+	user_id: u32,
+	#[varchar(size = 120, unique = true, null = false)]
+	user_name: String,
+	#[varchar(size = 30)]
+	last_name: String,
+	#[integer()]
+	birthday: Option<i64>,
+}
+
+#[tokio::test]
+async fn sqlite() {
+	let pool = common::sqlite_pool().await;
+	test(&AsyncUsers::from_sqlx_sqlite(&pool)).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+async fn postgres() {
+	let pool = common::sqlx_postgres_pool("worm_sqlx_postgres").await;
+	test(&AsyncUsers::from_sqlx_postgres(&pool)).await.unwrap();
+}
+
+async fn test(db: &AsyncUsersAsyncTable<'_>) -> Result<(), Box<dyn Error>> {
+	db.create_table().await?;
+	db.delete_all().await?;
+	
+	let john = AsyncUsers {
+		user_id: 4,
+		user_name: String::from("John"),
+		last_name: String::from("O'Brien"),
+		birthday: Some(214014012),
+	};
+	db.insert(&john).await?;
+	db.insert(&AsyncUsers {
+		user_id: 5,
+		user_name: String::from("Jane"),
+		last_name: String::from("Bin"),
+		birthday: None,
+	})
+	.await?;
+	
+	assert!(matches!(db.insert(&john).await, Err(worm::Error::PrimaryKeyViolation(_))));
+	assert!(matches!(
+		db.insert(&AsyncUsers { user_id: 6, ..john.clone() }).await,
+		Err(worm::Error::UniqueViolation(_))
+	));
+	
+	assert_eq!(db.count_all().await?, 2);
+	assert_eq!(db.count(AsyncUsersFilter::LastNameEqual(String::from("O'Brien")).into()).await?, 1);
+	assert_eq!(db.count(AsyncUsersFilter::BirthdayIsNull.into()).await?, 1);
+	assert!(matches!(
+		db.select_page(AsyncUsersSelectBuilder::default().build(), usize::MAX, 2).await,
+		Err(worm::Error::Conversion(_))
+	));
+	
+	let jane = db
+		.select_one(AsyncUsersFilter::UserIdGreaterThan(4).into())
+		.await?
+		.ok_or("Unable to find Jane")?;
+	assert_eq!(jane.user_name, "Jane");
+	assert_eq!(jane.birthday, None);
+	
+	let update = AsyncUsers { birthday: Some(123), ..john.clone() };
+	db.update_to(&john, &update).await?;
+	let john = db
+		.select(AsyncUsersFilter::UserNameEqual(String::from("John")).into())
+		.await?
+		.into_iter()
+		.next()
+		.ok_or("Unable to find John")?;
+	assert_eq!(john.birthday, Some(123));
+	
+	let key = AsyncUsersKey { user_id: 5 };
+	db.update_by_key(&key, &AsyncUsers { last_name: String::from("Doe"), ..jane })
+		.await?;
+	let jane = db.get_by_key(key.clone()).await?.ok_or("Unable to find Jane")?;
+	assert_eq!(jane.last_name, "Doe");
+	db.delete_by_key(key).await?;
+	
+	db.delete(AsyncUsersFilter::UserIdEqual(4).into()).await?;
+	assert_eq!(db.count_all().await?, 0);
+	
+	db.delete_table().await?;
+	Ok(())
+}
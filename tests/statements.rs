@@ -1,4 +1,5 @@
 use worm;
+use worm::{Dialect, Value};
 
 #[derive(worm::Table)]
 pub struct Users {
@@ -24,26 +25,68 @@ fn main() {
 	assert_eq!(Users::insert(), "INSERT INTO Users (user_id, user_name, first_name, last_name, note, birthday) VALUES (?1, ?2, ?3, ?4, ?5, ?6)");
 	assert_eq!(Users::update_by_id(), "UPDATE Users SET user_id = ?2, user_name = ?3, first_name = ?4, last_name = ?5, note = ?6, birthday = ?7 WHERE user_id = ?1");
 	
-	let filter = Filter::UserIdEqual(1);
-	assert_eq!(Users::select(filter.clone().into()), "SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users WHERE user_id = 1");
+	let filter = UsersFilter::UserIdEqual(1);
 	assert_eq!(
-		Users::delete(filter.clone().into()),
-		"DELETE FROM Users WHERE user_id = 1"
+		Users::select(filter.clone().into()).unwrap(),
+		(
+			"SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users WHERE user_id = ?1".to_string(),
+			vec![Value::Integer(1)]
+		)
+	);
+	assert_eq!(
+		Users::delete(filter.clone().into()).unwrap(),
+		("DELETE FROM Users WHERE user_id = ?1".to_string(), vec![Value::Integer(1)])
 	);
 	
-	let filter = Filter::And(
-		Box::new(Filter::UserIdEqual(1)),
-		Box::new(Filter::LastNameEqual(String::from("Dane"))),
+	let filter = UsersFilter::And(
+		Box::new(UsersFilter::UserIdEqual(1)),
+		Box::new(UsersFilter::LastNameEqual(String::from("O'Dane"))),
+	);
+	assert_eq!(
+		Users::select(filter.clone().into()).unwrap(),
+		(
+			"SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users WHERE (user_id = ?1 AND last_name = ?2)".to_string(),
+			vec![Value::Integer(1), Value::Text(String::from("O'Dane"))]
+		)
+	);
+	assert_eq!(
+		Users::delete(filter.clone().into()).unwrap(),
+		(
+			"DELETE FROM Users WHERE (user_id = ?1 AND last_name = ?2)".to_string(),
+			vec![Value::Integer(1), Value::Text(String::from("O'Dane"))]
+		)
 	);
-	assert_eq!(Users::select(filter.clone().into()), "SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users WHERE (user_id = 1 AND last_name = 'Dane')");
 	assert_eq!(
-		Users::delete(filter.clone().into()),
-		"DELETE FROM Users WHERE (user_id = 1 AND last_name = 'Dane')"
+		Users::count_statement(filter.into()).unwrap(),
+		(
+			"SELECT COUNT(*) FROM Users WHERE (user_id = ?1 AND last_name = ?2)".to_string(),
+			vec![Value::Integer(1), Value::Text(String::from("O'Dane"))]
+		)
 	);
 	
 	assert_eq!(
 		Users::select_all(),
-		"SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users"
+		(
+			"SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users".to_string(),
+			vec![]
+		)
+	);
+	assert_eq!(Users::delete_all(), ("DELETE FROM Users".to_string(), vec![]));
+	
+	// The same statements rendered for PostgreSQL
+	assert_eq!(Users::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Users ( user_id BIGINT NOT NULL PRIMARY KEY, user_name VARCHAR(120) NOT NULL UNIQUE, first_name VARCHAR(30), last_name VARCHAR(30), note TEXT, birthday BIGINT NULL )");
+	assert_eq!(Users::insert_for(Dialect::Postgres), "INSERT INTO Users (user_id, user_name, first_name, last_name, note, birthday) VALUES ($1, $2, $3, $4, $5, $6)");
+	assert_eq!(Users::update_by_id_for(Dialect::Postgres), "UPDATE Users SET user_id = $2, user_name = $3, first_name = $4, last_name = $5, note = $6, birthday = $7 WHERE user_id = $1");
+	
+	let filter = UsersFilter::Or(
+		Box::new(UsersFilter::UserIdEqual(1)),
+		Box::new(UsersFilter::LastNameEqual(String::from("O'Dane"))),
+	);
+	assert_eq!(
+		Users::select_for(Dialect::Postgres, filter.into()).unwrap(),
+		(
+			"SELECT user_id, user_name, first_name, last_name, note, birthday FROM Users WHERE (user_id = $1 OR  last_name = $2)".to_string(),
+			vec![Value::Integer(1), Value::Text(String::from("O'Dane"))]
+		)
 	);
-	assert_eq!(Users::delete_all(), "DELETE FROM Users");
 }
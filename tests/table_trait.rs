@@ -0,0 +1,64 @@
+use worm::Table;
+
+#[derive(Table, Clone)]
+pub struct Person {
+	#[integer(primary = true)]
+	id: u32,
+	#[text()]
+	name: String,
+}
+
+#[derive(Table, Clone)]
+pub struct Pet {
+	#[integer(primary = true)]
+	id: u32,
+	#[text()]
+	species: String,
+}
+
+/// Create the table and fill it with `rows`, returning the number of rows stored
+fn seed<T: Table>(table: &T, rows: &[T::Row]) -> usize {
+	table.create_table().unwrap();
+	table.delete_all().unwrap();
+	for row in rows {
+		table.insert(row).unwrap();
+	}
+	table.count_all().unwrap()
+}
+
+fn count_matching<T: Table>(table: &T, filter: T::Filter) -> usize {
+	table.count(filter.into()).unwrap()
+}
+
+#[test]
+fn main() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	
+	let persons = Person::from_rusqlite(&conn);
+	let stored = seed(
+		&persons,
+		&[
+			Person {
+				id: 1,
+				name: "John".to_string(),
+			},
+			Person {
+				id: 2,
+				name: "Jane".to_string(),
+			},
+		],
+	);
+	assert_eq!(stored, 2);
+	assert_eq!(count_matching(&persons, PersonFilter::NameEqual("Jane".to_string())), 1);
+	
+	let pets = Pet::from_rusqlite(&conn);
+	let stored = seed(
+		&pets,
+		&[Pet {
+			id: 1,
+			species: "Cat".to_string(),
+		}],
+	);
+	assert_eq!(stored, 1);
+	assert_eq!(count_matching(&pets, PetFilter::SpeciesEqual("Dog".to_string())), 0);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "time")]
+
+use time::macros::{date, datetime};
+use time::{Date, OffsetDateTime};
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Shift {
+	#[integer(primary = true)]
+	id: u32,
+	#[datetime()]
+	start: OffsetDateTime,
+	#[datetime(storage = "unix")]
+	end: OffsetDateTime,
+	#[date()]
+	day: Date,
+	#[date(storage = "unix")]
+	paid: Date,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Shift::create_table(), "CREATE TABLE IF NOT EXISTS Shift ( id INTEGER NOT NULL PRIMARY KEY, start TEXT, end INTEGER, day TEXT, paid INTEGER )");
+	
+	// Text is rendered in UTC whatever the offset of the value
+	assert_eq!(
+		Shift::count_statement(ShiftFilter::StartEqual(datetime!(2024-05-01 10:00 +02:00)).into()).unwrap(),
+		(
+			"SELECT COUNT(*) FROM Shift WHERE start = ?1".to_string(),
+			vec![worm::Value::Text("2024-05-01T08:00:00.000000000Z".to_string())]
+		)
+	);
+	assert_eq!(
+		Shift::count_statement(ShiftFilter::PaidEqual(date!(1970-01-02)).into()).unwrap().1,
+		vec![worm::Value::Integer(86400)]
+	);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Shift::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let morning = Shift {
+		id: 1,
+		start: datetime!(2024-05-01 06:00 UTC),
+		end: datetime!(2024-05-01 14:00 UTC),
+		day: date!(2024-05-01),
+		paid: date!(2024-05-31),
+	};
+	let night = Shift {
+		id: 2,
+		start: datetime!(2024-05-01 22:00 UTC),
+		end: datetime!(2024-05-02 06:00 UTC),
+		day: date!(2024-05-01),
+		paid: date!(2024-06-30),
+	};
+	db.insert(&morning).unwrap();
+	db.insert(&night).unwrap();
+	
+	assert_eq!(db.select_one(ShiftFilter::IdEqual(2).into()).unwrap(), Some(night));
+	assert_eq!(db.count(ShiftFilter::StartGreaterThan(datetime!(2024-05-01 12:00 +02:00)).into()).unwrap(), 1);
+	assert_eq!(db.count(ShiftFilter::EndLowerEqualThan(datetime!(2024-05-01 14:00 UTC)).into()).unwrap(), 1);
+	assert_eq!(db.count(ShiftFilter::DayEqual(date!(2024-05-01)).into()).unwrap(), 2);
+	assert_eq!(db.count(ShiftFilter::PaidGreaterThan(date!(2024-06-01)).into()).unwrap(), 1);
+}
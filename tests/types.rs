@@ -0,0 +1,49 @@
+use worm::{Dialect, Value};
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Telemetry {
+	#[integer(primary = true)]
+	id: u32,
+	#[real()]
+	temperature: f32,
+	#[real(null = true)]
+	pressure: Option<f64>,
+	#[boolean()]
+	online: bool,
+	#[blob()]
+	frame: Vec<u8>,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Telemetry::create_table(), "CREATE TABLE IF NOT EXISTS Telemetry ( id INTEGER NOT NULL PRIMARY KEY, temperature REAL, pressure REAL NULL, online BOOLEAN, frame BLOB )");
+	assert_eq!(Telemetry::create_table_for(Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Telemetry ( id BIGINT NOT NULL PRIMARY KEY, temperature DOUBLE PRECISION, pressure DOUBLE PRECISION NULL, online BOOLEAN, frame BYTEA )");
+	
+	assert_eq!(
+		Telemetry::count_statement(TelemetryFilter::OnlineIsFalse.into()).unwrap(),
+		("SELECT COUNT(*) FROM Telemetry WHERE online IS FALSE".to_string(), vec![])
+	);
+	assert_eq!(
+		Telemetry::delete(TelemetryFilter::FrameEqual(vec![1, 2]).into()).unwrap(),
+		("DELETE FROM Telemetry WHERE frame = ?1".to_string(), vec![Value::Blob(vec![1, 2])])
+	);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Telemetry::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let first = Telemetry { id: 1, temperature: 21.5, pressure: Some(1013.25), online: true, frame: vec![0xde, 0xad] };
+	db.insert(&first).unwrap();
+	db.insert(&Telemetry { id: 2, temperature: -4.0, pressure: None, online: false, frame: vec![] }).unwrap();
+	
+	assert_eq!(db.select_one(TelemetryFilter::OnlineIsTrue.into()).unwrap(), Some(first));
+	assert_eq!(db.count(TelemetryFilter::OnlineIsFalse.into()).unwrap(), 1);
+	assert_eq!(db.count(TelemetryFilter::OnlineEqual(true).into()).unwrap(), 1);
+	assert_eq!(db.count(TelemetryFilter::TemperatureLowerThan(0.0).into()).unwrap(), 1);
+	assert_eq!(db.count(TelemetryFilter::PressureGreaterEqualThan(1000.0).into()).unwrap(), 1);
+	assert_eq!(db.count(TelemetryFilter::FrameEqual(vec![0xde, 0xad]).into()).unwrap(), 1);
+	assert_eq!(db.count(TelemetryFilter::PressureIsNull.into()).unwrap(), 1);
+}
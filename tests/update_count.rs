@@ -0,0 +1,129 @@
+mod common;
+
+use worm::Dialect;
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Measure {
+	#[integer(primary = true)]
+	id: u32,
+	#[real()]
+	value: f64,
+	#[text(null = true)]
+	unit: Option<String>,
+}
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Tag {
+	#[text()]
+	label: String,
+	#[text(null = true)]
+	color: Option<String>,
+}
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Reading {
+	#[text()]
+	sensor: String,
+	#[real()]
+	value: f64,
+}
+
+#[test]
+fn statements() {
+	assert_eq!(Measure::update_to(), "UPDATE Measure SET id = ?2, value = ?3, unit = ?4 WHERE id = ?1");
+	assert_eq!(Tag::update_to(), "UPDATE Tag SET label = ?3, color = ?4 WHERE label IS ?1 AND color IS ?2");
+	assert_eq!(Tag::update_to_for(Dialect::Postgres), "UPDATE Tag SET label = $3, color = $4 WHERE label IS NOT DISTINCT FROM $1 AND color IS NOT DISTINCT FROM $2");
+	assert_eq!(Reading::update_to_by(&[ReadingColumn::Sensor]).unwrap(), "UPDATE Reading SET sensor = ?2, value = ?3 WHERE sensor IS ?1");
+	assert_eq!(
+		Reading::update_to_by_for(Dialect::Postgres, &[ReadingColumn::Value, ReadingColumn::Sensor]).unwrap(),
+		"UPDATE Reading SET sensor = $3, value = $4 WHERE value IS NOT DISTINCT FROM $1 AND sensor IS NOT DISTINCT FROM $2"
+	);
+	assert_eq!(Reading::update_to_by(&[]), None);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Measure::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	// A row holding a NULL or a float is matched on its primary key
+	let measure = Measure { id: 1, value: 0.1 + 0.2, unit: None };
+	db.insert(&measure).unwrap();
+	let update = Measure { unit: Some(String::from("m")), ..measure.clone() };
+	assert_eq!(db.update_to(&measure, &update).unwrap(), 1);
+	assert_eq!(db.update_by_id(&Measure { id: 2, ..update.clone() }).unwrap(), 0);
+	assert_eq!(db.select_all().unwrap(), vec![update.clone()]);
+	
+	let tags = Tag::from_rusqlite(&conn);
+	tags.create_table().unwrap();
+	let tag = Tag { label: String::from("urgent"), color: None };
+	tags.insert(&tag).unwrap();
+	assert_eq!(tags.update_to(&tag, &Tag { color: Some(String::from("red")), ..tag.clone() }).unwrap(), 1);
+	
+	// Strict mode fails the update of a single row which updated none
+	let db = db.strict();
+	assert!(matches!(
+		db.update_by_id(&Measure { id: 2, ..update.clone() }),
+		Err(worm::Error::NotFound)
+	));
+	assert!(matches!(
+		db.update_by_key(&MeasureKey { id: 3 }, &update),
+		Err(worm::Error::NotFound)
+	));
+	assert_eq!(db.update_by_id(&Measure { value: 2.0, ..update }).unwrap(), 1);
+	
+	// A float computed apart from the stored one doesn't match, unlike the columns nominated
+	let readings = Reading::from_rusqlite(&conn).strict();
+	readings.create_table().unwrap();
+	readings.insert(&Reading { sensor: String::from("north"), value: 0.3 }).unwrap();
+	let computed = Reading { sensor: String::from("north"), value: 0.1 + 0.2 };
+	let update = Reading { value: 0.5, ..computed.clone() };
+	assert!(matches!(readings.update_to(&computed, &update), Err(worm::Error::NotFound)));
+	assert_eq!(readings.update_to_by(&[ReadingColumn::Sensor], &computed, &update).unwrap(), 1);
+	assert_eq!(readings.select_all().unwrap(), vec![update]);
+	
+	// and the update of a single row which updated several
+	let tags = tags.strict();
+	let red = Tag { label: String::from("urgent"), color: Some(String::from("red")) };
+	tags.insert(&red).unwrap();
+	assert!(matches!(
+		tags.update_to(&red, &Tag { label: String::from("done"), ..red.clone() }),
+		Err(worm::Error::UnexpectedRowCount { expected: 1, actual: 2 })
+	));
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn postgres() {
+	let mut client = common::postgres_client("worm_update_count");
+	let tags = Tag::from_postgres(&mut client).strict();
+	tags.create_table().unwrap();
+	
+	let tag = Tag { label: String::from("urgent"), color: None };
+	tags.insert(&tag).unwrap();
+	let red = Tag { color: Some(String::from("red")), ..tag.clone() };
+	assert_eq!(tags.update_to(&tag, &red).unwrap(), 1);
+	assert!(matches!(tags.update_to(&tag, &red), Err(worm::Error::NotFound)));
+	
+	tags.delete_table().unwrap();
+}
+
+#[cfg(feature = "sqlx")]
+#[tokio::test]
+async fn sqlx_sqlite() {
+	let pool = common::sqlite_pool().await;
+	let db = Measure::from_sqlx_sqlite(&pool).strict();
+	db.create_table().await.unwrap();
+	
+	let measure = Measure { id: 1, value: 0.3, unit: None };
+	db.insert(&measure).await.unwrap();
+	let computed = Measure { value: 0.1 + 0.2, ..measure.clone() };
+	assert_eq!(db.update_to_by(&[MeasureColumn::Id], &computed, &measure).await.unwrap(), 1);
+	assert_eq!(db.update_to(&measure, &Measure { value: 0.4, ..measure.clone() }).await.unwrap(), 1);
+	assert!(matches!(
+		db.update_by_id(&Measure { id: 2, ..measure }).await,
+		Err(worm::Error::NotFound)
+	));
+}
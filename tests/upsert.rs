@@ -0,0 +1,119 @@
+mod common;
+
+use worm::Dialect;
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Preference {
+	#[text(primary = true)]
+	key: String,
+	#[text()]
+	value: String,
+	#[integer()]
+	revision: u32,
+}
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Subscriber {
+	#[integer(primary = true, auto = true)]
+	id: i64,
+	#[text(unique = true)]
+	email: String,
+	#[text()]
+	name: String,
+}
+
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Member {
+	#[integer(primary = true)]
+	id: i64,
+	#[text(unique = true)]
+	email: String,
+	#[text()]
+	name: String,
+}
+
+/// Table without conflict target, which has no upsert
+#[derive(worm::Table, Clone, Debug, PartialEq)]
+pub struct Visit {
+	#[text()]
+	page: String,
+}
+
+#[test]
+fn main() {
+	assert_eq!(Preference::upsert(&PreferenceUpsert::default()), "INSERT INTO Preference (key, value, revision) VALUES (?1, ?2, ?3) ON CONFLICT (key) DO UPDATE SET value = excluded.value, revision = excluded.revision");
+	let upsert = PreferenceUpsertBuilder::default().update(PreferenceColumn::Value).build();
+	assert_eq!(Preference::upsert_for(Dialect::Postgres, &upsert), "INSERT INTO Preference (key, value, revision) VALUES ($1, $2, $3) ON CONFLICT (key) DO UPDATE SET value = excluded.value");
+	let upsert = PreferenceUpsertBuilder::default().do_nothing().build();
+	assert_eq!(Preference::upsert(&upsert), "INSERT INTO Preference (key, value, revision) VALUES (?1, ?2, ?3) ON CONFLICT (key) DO NOTHING");
+	assert_eq!(Preference::insert_or_ignore_for(Dialect::Postgres), "INSERT INTO Preference (key, value, revision) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING");
+	
+	// The generated key is neither inserted nor updated
+	assert_eq!(Subscriber::upsert(&SubscriberUpsert::default()), "INSERT INTO Subscriber (email, name) VALUES (?1, ?2) ON CONFLICT (email) DO UPDATE SET name = excluded.name");
+	assert_eq!(Subscriber::upsert(&SubscriberUpsertBuilder::default().on_conflict(SubscriberColumn::Email).build()), "INSERT INTO Subscriber (email, name) VALUES (?1, ?2) ON CONFLICT (email) DO UPDATE SET name = excluded.name");
+	
+	// Nor is the primary key of a row conflicting on a unique column
+	let by_email = MemberUpsertBuilder::default().on_conflict(MemberColumn::Email).build();
+	assert_eq!(Member::upsert(&by_email), "INSERT INTO Member (id, email, name) VALUES (?1, ?2, ?3) ON CONFLICT (email) DO UPDATE SET name = excluded.name");
+	assert_eq!(Visit::insert_or_ignore(), "INSERT INTO Visit (page) VALUES (?1) ON CONFLICT DO NOTHING");
+	
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Preference::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	assert_eq!(db.upsert(&Preference { key: String::from("theme"), value: String::from("dark"), revision: 1 }).unwrap(), 1);
+	assert_eq!(db.upsert(&Preference { key: String::from("theme"), value: String::from("light"), revision: 2 }).unwrap(), 1);
+	assert_eq!(db.select_all().unwrap(), vec![Preference { key: String::from("theme"), value: String::from("light"), revision: 2 }]);
+	
+	// A conflicting row left untouched is not counted
+	let do_nothing = PreferenceUpsertBuilder::default().do_nothing().build();
+	assert_eq!(db.upsert_with(&Preference { key: String::from("theme"), value: String::from("dark"), revision: 3 }, do_nothing).unwrap(), 0);
+	
+	let value_only = PreferenceUpsertBuilder::default().update(PreferenceColumn::Value).build();
+	db.upsert_with(&Preference { key: String::from("theme"), value: String::from("solarized"), revision: 9 }, value_only).unwrap();
+	assert_eq!(db.select_all().unwrap(), vec![Preference { key: String::from("theme"), value: String::from("solarized"), revision: 2 }]);
+	
+	assert!(!db.insert_or_ignore(&Preference { key: String::from("theme"), value: String::from("dark"), revision: 3 }).unwrap());
+	assert!(db.insert_or_ignore(&Preference { key: String::from("font"), value: String::from("mono"), revision: 1 }).unwrap());
+	assert_eq!(db.count_all().unwrap(), 2);
+	
+	let subscribers = Subscriber::from_rusqlite(&conn);
+	subscribers.create_table().unwrap();
+	let by_email = || SubscriberUpsertBuilder::default().on_conflict(SubscriberColumn::Email).build();
+	let ann = Subscriber { id: 0, email: String::from("ann@example.com"), name: String::from("Ann") };
+	subscribers.upsert_with(&ann, by_email()).unwrap();
+	subscribers.upsert_with(&Subscriber { name: String::from("Anne"), ..ann.clone() }, by_email()).unwrap();
+	assert_eq!(subscribers.select_all().unwrap(), vec![Subscriber { id: 1, email: ann.email.clone(), name: String::from("Anne") }]);
+	
+	// The auto key is never inserted, so the same row conflicts on its unique column by default
+	let bea = Subscriber { id: 0, email: String::from("bea@example.com"), name: String::from("Bea") };
+	subscribers.upsert(&bea).unwrap();
+	subscribers.upsert(&bea).unwrap();
+	assert_eq!(subscribers.count_all().unwrap(), 2);
+	
+	let members = Member::from_rusqlite(&conn);
+	members.create_table().unwrap();
+	let by_email = || MemberUpsertBuilder::default().on_conflict(MemberColumn::Email).build();
+	let bob = Member { id: 7, email: String::from("bob@example.com"), name: String::from("Bob") };
+	members.upsert_with(&bob, by_email()).unwrap();
+	members.upsert_with(&Member { id: 8, name: String::from("Robert"), ..bob.clone() }, by_email()).unwrap();
+	assert_eq!(members.select_all().unwrap(), vec![Member { name: String::from("Robert"), ..bob }]);
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+#[ignore = "needs a PostgreSQL server at WORM_POSTGRES_URL"]
+fn postgres() {
+	let mut client = common::postgres_client("worm_upsert");
+	let db = Preference::from_postgres(&mut client);
+	db.create_table().unwrap();
+	
+	db.upsert(&Preference { key: String::from("theme"), value: String::from("dark"), revision: 1 }).unwrap();
+	db.upsert(&Preference { key: String::from("theme"), value: String::from("light"), revision: 2 }).unwrap();
+	assert_eq!(db.select_all().unwrap(), vec![Preference { key: String::from("theme"), value: String::from("light"), revision: 2 }]);
+	assert!(!db.insert_or_ignore(&Preference { key: String::from("theme"), value: String::from("dark"), revision: 3 }).unwrap());
+	assert!(db.insert_or_ignore(&Preference { key: String::from("font"), value: String::from("mono"), revision: 1 }).unwrap());
+	assert_eq!(db.count_all().unwrap(), 2);
+	
+	db.delete_table().unwrap();
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "uuid")]
+
+use uuid::Uuid;
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Account {
+	#[uuid(primary = true, generate = "v7")]
+	id: Uuid,
+	#[uuid(storage = "blob")]
+	owner: Uuid,
+	#[worm(storage = "blob")]
+	parent: Option<Uuid>,
+	referrer: Option<Uuid>,
+}
+
+#[derive(worm::Table, Debug, PartialEq)]
+pub struct Token {
+	#[uuid(primary = true, storage = "blob", generate = "v4")]
+	id: Uuid,
+	#[text()]
+	scope: String,
+}
+
+#[test]
+fn statement() {
+	assert_eq!(Account::create_table(), "CREATE TABLE IF NOT EXISTS Account ( id TEXT NOT NULL PRIMARY KEY, owner BLOB, parent BLOB NULL, referrer TEXT NULL )");
+	assert_eq!(Token::create_table_for(worm::Dialect::Postgres), "CREATE TABLE IF NOT EXISTS Token ( id BYTEA NOT NULL PRIMARY KEY, scope TEXT )");
+	
+	let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+	assert_eq!(
+		Account::delete(AccountFilter::Or(Box::new(AccountFilter::IdEqual(id)), Box::new(AccountFilter::OwnerEqual(id))).into()).unwrap(),
+		(
+			"DELETE FROM Account WHERE (id = ?1 OR  owner = ?2)".to_string(),
+			vec![worm::Value::Text("67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()), worm::Value::Blob(id.as_bytes().to_vec())]
+		)
+	);
+}
+
+#[test]
+fn sqlite() {
+	let conn = rusqlite::Connection::open_in_memory().unwrap();
+	let db = Account::from_rusqlite(&conn);
+	db.create_table().unwrap();
+	
+	let owner = Uuid::new_v4();
+	let account = Account { id: Uuid::new_v4(), owner, parent: Some(Uuid::new_v4()), referrer: None };
+	db.insert(&account).unwrap();
+	assert_eq!(db.select_one(AccountFilter::IdEqual(account.id).into()).unwrap().as_ref(), Some(&account));
+	assert_eq!(db.count(AccountFilter::OwnerEqual(owner).into()).unwrap(), 1);
+	assert_eq!(db.count(AccountFilter::ParentEqual(account.parent.unwrap()).into()).unwrap(), 1);
+	assert_eq!(db.count(AccountFilter::ReferrerIsNull.into()).unwrap(), 1);
+	
+	// A nil id is replaced by a generated one, returned by insert
+	let id = db.insert(&Account { id: Uuid::nil(), owner, parent: None, referrer: Some(account.id) }).unwrap();
+	assert_eq!(id.get_version(), Some(uuid::Version::SortRand));
+	let generated = db.select_one(AccountFilter::IdEqual(id).into()).unwrap().unwrap();
+	assert_eq!(generated.referrer, Some(account.id));
+	assert_eq!(db.count(AccountFilter::OwnerEqual(owner).into()).unwrap(), 2);
+	// while an explicit id is returned as is
+	let id = Uuid::new_v4();
+	assert_eq!(db.insert(&Account { id, owner, parent: None, referrer: None }).unwrap(), id);
+	
+	let tokens = Token::from_rusqlite(&conn);
+	tokens.create_table().unwrap();
+	let read = tokens.insert(&Token { id: Uuid::nil(), scope: "read".to_string() }).unwrap();
+	tokens.insert(&Token { id: Uuid::nil(), scope: "write".to_string() }).unwrap();
+	let all = tokens.select_all().unwrap();
+	assert_eq!(all.len(), 2);
+	assert!(all.iter().all(|t| t.id.get_version() == Some(uuid::Version::Random)));
+	
+	// An upsert conflicts on the id of the row, which is not regenerated
+	tokens.upsert(&Token { id: read, scope: "admin".to_string() }).unwrap();
+	tokens.upsert(&Token { id: Uuid::nil(), scope: "none".to_string() }).unwrap();
+	tokens.upsert(&Token { id: Uuid::nil(), scope: "nil".to_string() }).unwrap();
+	assert_eq!(tokens.count_all().unwrap(), 3);
+	assert_eq!(tokens.get_by_key(TokenKey { id: read }).unwrap().unwrap().scope, "admin");
+	assert_eq!(tokens.get_by_key(TokenKey { id: Uuid::nil() }).unwrap().unwrap().scope, "nil");
+}